@@ -9,8 +9,29 @@ local M = {}
 
 M.expect_second_run = false
 
+local highlight_ns = vim.api.nvim_create_namespace "xbase_highlight"
+
+local function clear_highlights()
+  for _, bufnr in ipairs(vim.api.nvim_list_bufs()) do
+    vim.api.nvim_buf_clear_namespace(bufnr, highlight_ns, 0, -1)
+  end
+end
+
+local function highlight_range(args)
+  local bufnr = vim.fn.bufadd(args.file)
+  vim.fn.bufload(bufnr)
+  for line = args.start_line, args.end_line do
+    local col_start = line == args.start_line and args.start_col or 0
+    local col_end = line == args.end_line and args.end_col or -1
+    vim.api.nvim_buf_add_highlight(bufnr, highlight_ns, args.group, line - 1, col_start, col_end)
+  end
+end
+
 local function task_set(args)
   M.has_task = true
+  if tkind.is_build(args.kind) then
+    clear_highlights()
+  end
   local running, _ = tkind:prefix(args.kind)
   args.prefix = running
   vim.g.xbase_ctask = args
@@ -88,6 +109,24 @@ local function task_finish(args)
   end)
 end
 
+local function statusline_update(args)
+  -- NOTE: don't override status of a task still in progress
+  if M.has_task or vim.g.xbase_ctask == nil then
+    return
+  end
+  vim.g.xbase_ctask = vim.tbl_extend("force", vim.g.xbase_ctask, args)
+  if tstatus.is_idle(args.status) and not tkind.is_run(vim.g.xbase_ctask.kind) then
+    vim.g.xbase_ctask_display = nil
+    vim.g.xbase_ctask_line = nil
+  end
+  if tstatus.is_error(args.status) then
+    local icon = config.statusline.failure.icon
+    local line = ("%s [%s] %s"):format(icon, vim.g.xbase_ctask.target, args.status.Error)
+    vim.g.xbase_ctask_line = line
+    vim.g.xbase_ctask_display = line
+  end
+end
+
 function M.start(root, address)
   local socket = socket:connect(address)
 
@@ -99,7 +138,11 @@ function M.start(root, address)
 
   socket:read_start(function(chunk)
     local chunk = vim.trim(chunk)
-    for _, chunk in ipairs(vim.split(chunk, "\n")) do
+    local messages = vim.tbl_filter(function(line)
+      return line ~= ""
+    end, vim.split(chunk, "\n"))
+
+    for _, chunk in ipairs(messages) do
       local item = vim.json.decode(chunk)
       local type, args = item.type, item.args
 
@@ -116,6 +159,15 @@ function M.start(root, address)
           return task_finish(args)
         end
 
+        if msg.is_update_target_list(type) then
+          vim.g.xbase_targets = args
+          return
+        end
+
+        if msg.is_update_statusline(type) then
+          return statusline_update(args)
+        end
+
         if msg.is_notify(type) then
           notify(args.content, args.level)
           if string.find(args.content, "Registered") ~= nil then
@@ -129,13 +181,27 @@ function M.start(root, address)
         end
 
         if msg.is_reload_lsp_server(type) then
-          return vim.cmd "LspRestart"
+          if args == nil or args == vim.NIL then
+            return vim.cmd "LspRestart"
+          end
+          for _, client in ipairs(vim.lsp.get_active_clients()) do
+            if vim.tbl_contains(args, client.name) then
+              vim.lsp.stop_client(client.id)
+            end
+          end
+          return vim.defer_fn(function()
+            vim.cmd "LspStart"
+          end, 500)
         end
 
         if msg.is_open_logger(type) then
           return logger.toggle(nil, false)
         end
 
+        if msg.is_clear_logger(type) then
+          return logger.clear()
+        end
+
         if msg.is_log(type) then
           return logger.log(args.content, args.level)
         end
@@ -150,6 +216,51 @@ function M.start(root, address)
           return
         end
 
+        if msg.is_execute_shell_command(type) then
+          if not config.allow_shell_tasks then
+            return notify.warn(("Ignoring shell command `%s`, allow_shell_tasks is disabled"):format(args))
+          end
+          local on_output = function(_, lines)
+            for _, line in ipairs(lines) do
+              if #line ~= 0 then
+                logger.log(line, "Info")
+              end
+            end
+          end
+          vim.fn.jobstart(args, { cwd = root, on_stdout = on_output, on_stderr = on_output })
+          return
+        end
+
+        if msg.is_set_variable(type) then
+          vim.g[args.key] = args.value
+          return
+        end
+
+        if msg.is_diagnostic(type) then
+          local bufnr = vim.fn.bufadd(args.path)
+          local ns = vim.api.nvim_create_namespace "xbase"
+          local diagnostics = vim.tbl_filter(function(d)
+            return d.message ~= args.content
+          end, vim.diagnostic.get(bufnr, { namespace = ns }))
+          table.insert(diagnostics, {
+            lnum = args.line - 1,
+            col = 0,
+            message = args.content,
+            severity = vim.diagnostic.severity[string.upper(args.level)] or vim.diagnostic.severity.HINT,
+            source = "xbase",
+          })
+          vim.diagnostic.set(ns, bufnr, diagnostics)
+          return
+        end
+
+        if msg.is_highlight_range(type) then
+          return highlight_range(args)
+        end
+
+        if msg.is_reveal_file(type) then
+          return vim.cmd("edit " .. vim.fn.fnameescape(args))
+        end
+
         ---@diagnostic disable-next-line: empty-block
         if msg.is_set_watching(type) then
           -- ()
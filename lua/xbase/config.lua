@@ -9,12 +9,15 @@ config.values = _XBASECONFIG
 local defaults = {
   --- Log level. Set to ERROR to ignore everything
   log_level = vim.log.levels.DEBUG,
+  --- Whether the daemon is allowed to run shell commands through neovim
+  allow_shell_tasks = false,
   --- Statusline provider configurations
   statusline = {
     watching = { icon = "", color = "#1abc9c" },
     device_running = { icon = "", color = "#4a6edb" },
     success = { icon = "", color = "#1abc9c" },
     failure = { icon = "", color = "#db4b4b" },
+    warning = { icon = "", color = "#e0af68" },
     show_progress = true, -- TODO: make show_progress = false actually work
   },
   --- TODO(nvim): Limit devices platform to select from
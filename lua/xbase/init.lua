@@ -18,6 +18,17 @@ local function try_attach(root)
       initialized = true
       autocmd({ "BufEnter", "BufWinEnter" }, { pattern = file_patterns, callback = try_attach_mappings })
       autocmd({ "BufEnter" }, { pattern = "xclog", callback = try_attach_mappings })
+      autocmd({ "VimLeavePre" }, {
+        callback = function()
+          server.drop()
+        end,
+      })
+      vim.api.nvim_create_user_command("XBaseOpenXcode", function()
+        server.open_xcode(vim.loop.cwd())
+      end, {})
+      vim.api.nvim_create_user_command("XBaseReadme", function()
+        server.readme(vim.loop.cwd())
+      end, {})
     end
     try_attach_mappings()
   end
@@ -87,6 +87,14 @@ function M.log(msg, level)
   end
 end
 
+--- Clear Log buffer content
+function M.clear()
+  if not M.bufnr then
+    return
+  end
+  vim.api.nvim_buf_set_lines(M.bufnr, 0, -1, false, {})
+end
+
 function M.update_cursor_position(line_count)
   local winid, is_focused = M.window()
 
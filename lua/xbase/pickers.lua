@@ -63,7 +63,7 @@ local insert_entry = function(acc, picker, command, target, configuration, watch
   acc[#acc + 1] = item
 end
 
-local get_selections = function(root, picker)
+local get_selections = function(root, picker, platform)
   local project_info = require("xbase.state").project_info[root]
   local commands = picker == C.Watch and { C.Build, C.Run } or { picker }
   local targets = project_info.targets
@@ -78,14 +78,20 @@ local get_selections = function(root, picker)
 
   for _, command in ipairs(commands) do
     for target, info in pairs(targets) do
-      for _, configuration in ipairs(info.configurations) do
-        local devices = state.runners[info.platform]
-        if include_devices and command == C.Run and not (devices == nil or #devices == 0) then
-          for _, device in ipairs(devices) do
-            insert_entry(results, picker, command, target, configuration, watchlist, device)
+      if platform == nil or info.platform == platform then
+        local configurations = info.configurations
+        if configurations == nil or #configurations == 0 then
+          configurations = project_info.configurations or {}
+        end
+        for _, configuration in ipairs(configurations) do
+          local devices = state.runners[info.platform]
+          if include_devices and command == C.Run and not (devices == nil or #devices == 0) then
+            for _, device in ipairs(devices) do
+              insert_entry(results, picker, command, target, configuration, watchlist, device)
+            end
+          else
+            insert_entry(results, picker, command, target, configuration, watchlist)
           end
-        else
-          insert_entry(results, picker, command, target, configuration, watchlist)
         end
       end
     end
@@ -147,14 +153,40 @@ local entry_maker = function(entry)
   return entry
 end
 
-local find = function(name, opts)
+--- Ask user to pick one of project targets platforms
+local select_platform = function(targets, cb)
+  local platforms, seen = {}, {}
+  for _, info in pairs(targets or {}) do
+    if not seen[info.platform] then
+      seen[info.platform] = true
+      platforms[#platforms + 1] = info.platform
+    end
+  end
+  table.sort(platforms)
+  vim.ui.select(platforms, { prompt = "Select Platform" }, function(platform)
+    if platform then
+      cb(platform)
+    end
+  end)
+end
+
+local function find(name, opts)
   opts = themes.get_dropdown(opts or {})
   opts.root = opts.root or vim.loop.cwd()
+  local project_info = state.project_info[opts.root]
+
+  if project_info and project_info.is_multi_platform and not opts.platform then
+    return select_platform(project_info.targets, function(platform)
+      opts.platform = platform
+      find(name, opts)
+    end)
+  end
+
   picker(opts, {
     prompt_title = opts.name,
     sorter = sorter {},
     finder = finder {
-      results = get_selections(opts.root, name),
+      results = get_selections(opts.root, name, opts.platform),
       entry_maker = entry_maker,
     },
     attach_mappings = mappings,
@@ -26,7 +26,7 @@ function M.spawn_daemon(cb)
     detached = true,
   })
   stdout:read_start(vim.schedule_wrap(function(_, _)
-    M.socket = socket:connect(constants.SOCK_ADDR)
+    M.socket = socket:connect(constants.SOCK_ADDR, M.on_socket_error)
     stdout:read_stop()
     cb()
   end))
@@ -38,32 +38,56 @@ function M.ensure_connection(cb)
     if uv.fs_stat(constants.SOCK_ADDR) == nil then
       return M.spawn_daemon(cb)
     else
-      M.socket = socket:connect(constants.SOCK_ADDR)
+      M.socket = socket:connect(constants.SOCK_ADDR, M.on_socket_error)
     end
   end
   cb()
 end
 
+---Drop failed socket connection and notify the user
+---@param err string
+---@param sock? table socket that failed, defaults to current socket
+function M.on_socket_error(err, sock)
+  sock = sock or M.socket
+  if sock ~= nil then
+    pcall(sock.close, sock)
+  end
+  if M.socket == sock then
+    M.socket = nil
+  end
+  notify.error(string.format("xbase daemon unavailable: %s", err))
+end
+
 ---Send Request to socket, and on response call on_response with data if no error
 ---@param req table
 ---@param on_response? function(response:table)
 function M.request(req, on_response)
   M.ensure_connection(function()
-    M.socket:read_start(function(chunk)
-      vim.schedule(function()
-        local res = vim.json.decode(chunk)
-        if res.error then
-          notify.error(string.format("%s %s", res.error.kind, res.error.msg))
-          return
-        else
-          if on_response then
-            on_response(res.data)
+    local sock = M.socket
+    local ok, err = pcall(function()
+      if sock:error() then
+        error(sock:error())
+      end
+      sock:read_start(function(chunk)
+        vim.schedule(function()
+          local res = vim.json.decode(chunk)
+          if res.error then
+            notify.error(string.format("%s %s", res.error.kind, res.error.msg))
+            return
+          else
+            if on_response then
+              on_response(res.data)
+            end
           end
-        end
+        end)
+        sock:read_stop()
       end)
-      M.socket:read_stop()
+      sock:write(req)
     end)
-    M.socket:write(req)
+    -- NOTE: stream errors are already reported through on_socket_error
+    if not ok and not sock:error() then
+      M.on_socket_error(err, sock)
+    end
   end)
 end
 
@@ -101,10 +125,35 @@ function M.register(root)
   end)
 end
 
+---Request available devices from daemon and call cb with the device list
+---@param cb function(devices:table[])
+function M.request_device_list(cb)
+  M.request({ method = "devices", args = {} }, cb)
+end
+
+---Open a given root in Xcode
+---@param root string
+function M.open_xcode(root)
+  M.request { method = "open_xcode", args = { root = root } }
+end
+
+---Reveal README of a given root
+---@param root string
+function M.readme(root)
+  M.request { method = "readme", args = { root = root } }
+end
+
 ---Drop a given root or drop all tracked roots if root is nil
 ---@param root string
 function M.drop(root)
-  M.request { method = "drop", args = { roots = { root } } }
+  local roots = root and { root } or vim.tbl_keys(M.roots)
+  if vim.tbl_isempty(roots) or M.socket == nil then
+    return
+  end
+  M.request { method = "drop", args = { id = id, roots = roots } }
+  for _, r in ipairs(roots) do
+    M.roots[r] = nil
+  end
 end
 
 return M
@@ -3,25 +3,49 @@ local uv = require "luv"
 ---@class XBaseSocket @Object to communcate with xbase sockets
 ---@field _socket any
 ---@field _stream_error any
+---@field _on_error function(err:string, socket:table)|nil
 local M = {}
 M.__index = M
 
-function M:connect(address)
+---Connect to address, calling on_error (in main loop) on first stream failure
+---@param address string
+---@param on_error? function(err:string, socket:table)
+function M:connect(address, on_error)
   local socket = uv.new_pipe(false)
-  local self = setmetatable({ _socket = socket, _stream_error = nil }, M)
+  local self = setmetatable({ _socket = socket, _stream_error = nil, _on_error = on_error }, M)
   socket:connect(address, function(err)
-    self._stream_error = self._stream_error or err
+    if err then
+      self:_fail(err)
+    end
   end)
   return self
 end
 
+---Record stream error and notify on_error once
+function M:_fail(err)
+  if self._stream_error then
+    return
+  end
+  self._stream_error = err
+  if self._on_error then
+    vim.schedule(function()
+      self._on_error(err, self)
+    end)
+  end
+end
+
+---Get stream error if any
+function M:error()
+  return self._stream_error
+end
+
 function M:write(data)
   if self._stream_error then
     error(self._stream_error)
   end
   uv.write(self._socket, vim.json.encode(data), function(err)
     if err then
-      print(self._stream_error or err)
+      self:_fail(err)
     end
   end)
 end
@@ -32,7 +56,7 @@ function M:read_start(cb)
   end
   self._socket:read_start(function(err, chunk)
     if err then
-      error(err)
+      self:_fail(err)
     elseif chunk ~= nil then
       vim.schedule(function()
         cb(chunk)
@@ -42,8 +66,9 @@ function M:read_start(cb)
 end
 
 function M:read_stop()
+  -- NOTE: errored streams are no longer reading
   if self._stream_error then
-    error(self._stream_error)
+    return
   end
   uv.read_stop(self._socket)
 end
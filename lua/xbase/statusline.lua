@@ -66,8 +66,10 @@ function M.feline()
 
         if tstatus.is_succeeded(status) then
           data.hl = { fg = statusline_cfg.success.color }
-        elseif tstatus.is_failed(status) then
+        elseif tstatus.is_failed(status) or tstatus.is_error(status) then
           data.hl = { fg = statusline_cfg.failure.color }
+        elseif tstatus.is_warning(status) then
+          data.hl = { fg = statusline_cfg.warning.color }
         elseif tkind.is_run(kind) then
           data.hl = { fg = statusline_cfg.device_running.color }
         else
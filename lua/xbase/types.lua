@@ -13,6 +13,15 @@ M.TaskKind = {
   is_run = function(kind)
     return kind == "Run"
   end,
+  is_clean = function(kind)
+    return kind == "Clean"
+  end,
+  is_test = function(kind)
+    return kind == "Test"
+  end,
+  is_profile = function(kind)
+    return kind == "Profile"
+  end,
   prefix = function(self, kind)
     if self.is_compile(kind) then
       return "Compiling", "Compiled"
@@ -22,6 +31,12 @@ M.TaskKind = {
       return "Building", "Built"
     elseif self.is_run(kind) then
       return "Running", "Running"
+    elseif self.is_clean(kind) then
+      return "Cleaning", "Cleaned"
+    elseif self.is_test(kind) then
+      return "Testing", "Tested"
+    elseif self.is_profile(kind) then
+      return "Profile Building", "Profile Built"
     end
   end,
 }
@@ -36,6 +51,16 @@ M.TaskStatus = {
   is_processing = function(status)
     return status == "Processing"
   end,
+  is_warning = function(status)
+    return status == "Warning"
+  end,
+  is_idle = function(status)
+    return status == "Idle"
+  end,
+  ---Error status carries its cause, e.g. { Error = "compile error in main.swift" }
+  is_error = function(status)
+    return type(status) == "table" and status.Error ~= nil
+  end,
 }
 
 M.Message = {
@@ -45,8 +70,11 @@ M.Message = {
   is_open_logger = function(ty)
     return ty == "OpenLogger"
   end,
+  is_clear_logger = function(ty)
+    return ty == "ClearLogger"
+  end,
   is_log = function(ty)
-    return ty == "Log"
+    return ty == "Log" or ty == "LargeLog"
   end,
   is_reload_lsp_server = function(ty)
     return ty == "ReloadLspServer"
@@ -60,12 +88,33 @@ M.Message = {
   task_is_finish_current = function(ty)
     return ty == "FinishCurrentTask"
   end,
+  is_update_target_list = function(ty)
+    return ty == "UpdateTargetList"
+  end,
+  is_update_statusline = function(ty)
+    return ty == "UpdateStatusline"
+  end,
   is_set_watching = function(ty)
     return ty == "SetWatching"
   end,
   is_set_state = function(ty)
     return ty == "SetState"
   end,
+  is_set_variable = function(ty)
+    return ty == "SetVariable"
+  end,
+  is_execute_shell_command = function(ty)
+    return ty == "ExecuteShellCommand"
+  end,
+  is_diagnostic = function(ty)
+    return ty == "Diagnostic"
+  end,
+  is_highlight_range = function(ty)
+    return ty == "HighlightRange"
+  end,
+  is_reveal_file = function(ty)
+    return ty == "RevealFile"
+  end,
 }
 
 return M
@@ -30,7 +30,16 @@ fn gen_ts_types_file(path: PathBuf) {
     options.root_namespace = None;
     options.header = None;
 
-    type Requests = (Request, RunRequest, RegisterRequest, DropRequest);
+    type Requests = (
+        Request,
+        RunRequest,
+        RegisterRequest,
+        DropRequest,
+        BroadcastGlobalRequest,
+        DevicesRequest,
+        OpenXcodeRequest,
+        ReadmeRequest,
+    );
     type Responses = (Response, ServerError);
     type Transports = (
         ProjectInfo,
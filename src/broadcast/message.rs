@@ -1,9 +1,11 @@
 use crate::{BuildSettings, ProjectInfo, Runners};
 use serde::{Deserialize, Serialize};
+use std::{path::PathBuf, sync::Arc};
+use strum::Display;
 use typescript_type_def::TypeDef;
 
 /// State usesd to set client state
-#[derive(Debug, Serialize, TypeDef)]
+#[derive(Debug, Clone, Serialize, TypeDef)]
 #[serde(tag = "key", content = "value")]
 #[serde(rename_all = "camelCase")]
 pub enum State {
@@ -12,7 +14,7 @@ pub enum State {
 }
 
 /// Representation of Messages that clients needs to process
-#[derive(Debug, Serialize, TypeDef)]
+#[derive(Debug, Clone, Serialize, TypeDef)]
 #[serde(tag = "type", content = "args")]
 pub enum Message {
     /// Notify use with a message
@@ -24,10 +26,18 @@ pub enum Message {
         content: String,
         level: ContentLevel,
     },
+    /// Log message with shared content, cheap to clone for large process output
+    LargeLog {
+        #[type_def(type_of = "String")]
+        content: Arc<str>,
+        level: ContentLevel,
+    },
     /// Open Logger
     OpenLogger,
-    /// Reload Language server
-    ReloadLspServer,
+    /// Clear Logger
+    ClearLogger,
+    /// Reload Language servers with given names, or all servers if None
+    ReloadLspServer(Option<Vec<String>>),
     /// Set Current Task
     SetCurrentTask {
         kind: TaskKind,
@@ -42,6 +52,12 @@ pub enum Message {
     FinishCurrentTask {
         status: TaskStatus,
     },
+    /// Update client list of project targets
+    UpdateTargetList(Vec<String>),
+    /// Update statusline state
+    UpdateStatusline {
+        status: TaskStatus,
+    },
     /// Notify client that something is being watched
     SetWatching {
         watching: bool,
@@ -49,9 +65,37 @@ pub enum Message {
     },
     /// Notification to client to update a state with the given value
     SetState(State),
+    /// Execute a shell command on client side (opt-in, see allow_shell_tasks)
+    ExecuteShellCommand(String),
+    /// Set a client side variable (i.e. vim.g[key]) to a given value
+    SetVariable {
+        key: String,
+        value: String,
+    },
+    /// Report a diagnostic for a given file and line
+    Diagnostic {
+        path: String,
+        line: u32,
+        content: String,
+        level: ContentLevel,
+    },
+    /// Temporarily highlight a range in file (1-based lines, 0-based columns)
+    HighlightRange {
+        file: PathBuf,
+        start_line: u32,
+        start_col: u32,
+        end_line: u32,
+        end_col: u32,
+        group: String,
+    },
+    /// Open a given file in client editor
+    RevealFile(PathBuf),
     /// Internal!
     #[serde(skip)]
     Disconnect,
+    /// Internal! Messages written to clients at once, see `Broadcast::send_batch`
+    #[serde(skip)]
+    Batch(Vec<Message>),
 }
 
 /// What kind of task is currently under progress?
@@ -61,14 +105,23 @@ pub enum TaskKind {
     Build,
     /// Run Task
     Run,
+    /// Test Task
+    Test,
+    /// Build with debug symbols for profiling (i.e. Instruments)
+    Profile,
     /// Compile Project (maybe setup)
     Compile,
     /// Generate xcodeproj
     Generate,
+    /// Clean build artifacts
+    Clean,
 }
 
 /// What the status of task is currently under progress?
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, TypeDef)]
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Display, TypeDef,
+)]
+#[strum(serialize_all = "lowercase")]
 pub enum TaskStatus {
     /// Task Failed,
     Failed,
@@ -76,10 +129,58 @@ pub enum TaskStatus {
     Succeeded,
     /// Processing Task,
     Processing,
+    /// Non-fatal issue (e.g. lint warnings)
+    Warning,
+    /// No work in progress
+    Idle,
+    /// Task failed with a short human-readable cause
+    Error(ErrorCause),
+}
+
+impl TaskStatus {
+    /// Maximum length of [`TaskStatus::Error`] cause
+    pub const MAX_ERROR_LEN: usize = 80;
+
+    /// Create error status, truncating cause to [`TaskStatus::MAX_ERROR_LEN`] characters
+    pub fn error<S: AsRef<str>>(cause: S) -> Self {
+        Self::Error(ErrorCause::new(cause.as_ref()))
+    }
+}
+
+/// Cause of [`TaskStatus::Error`], only created through [`TaskStatus::error`]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, TypeDef)]
+pub struct ErrorCause(String);
+
+impl ErrorCause {
+    fn new(cause: &str) -> Self {
+        let cause = cause.trim();
+        if cause.chars().count() > TaskStatus::MAX_ERROR_LEN {
+            let cause = cause
+                .chars()
+                .take(TaskStatus::MAX_ERROR_LEN - 1)
+                .collect::<String>();
+            Self(format!("{cause}…"))
+        } else {
+            Self(cause.into())
+        }
+    }
+
+    /// Get cause as str
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorCause {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(|cause| Self::new(&cause))
+    }
 }
 
 /// What a given content level is? for whether to log/show it
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, TypeDef)]
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Display, TypeDef,
+)]
 pub enum ContentLevel {
     /// Trace Message
     Trace,
@@ -93,6 +194,121 @@ pub enum ContentLevel {
     Error,
 }
 
+impl ContentLevel {
+    /// Returns `true` if the level is as severe as or more severe than threshold
+    #[must_use]
+    pub fn is_at_least(&self, threshold: ContentLevel) -> bool {
+        *self >= threshold
+    }
+}
+
+impl From<ContentLevel> for tracing::Level {
+    fn from(level: ContentLevel) -> Self {
+        match level {
+            ContentLevel::Trace => Self::TRACE,
+            ContentLevel::Debug => Self::DEBUG,
+            ContentLevel::Info => Self::INFO,
+            ContentLevel::Warn => Self::WARN,
+            ContentLevel::Error => Self::ERROR,
+        }
+    }
+}
+
+impl From<tracing::Level> for ContentLevel {
+    fn from(level: tracing::Level) -> Self {
+        match level {
+            tracing::Level::TRACE => Self::Trace,
+            tracing::Level::DEBUG => Self::Debug,
+            tracing::Level::INFO => Self::Info,
+            tracing::Level::WARN => Self::Warn,
+            _ => Self::Error,
+        }
+    }
+}
+
+#[cfg(feature = "log-integration")]
+impl From<log::Level> for ContentLevel {
+    fn from(level: log::Level) -> Self {
+        match level {
+            log::Level::Error => Self::Error,
+            log::Level::Warn => Self::Warn,
+            log::Level::Info => Self::Info,
+            log::Level::Debug => Self::Debug,
+            log::Level::Trace => Self::Trace,
+        }
+    }
+}
+
+impl Message {
+    /// Create log message with a given level
+    pub fn log<S: AsRef<str>>(content: S, level: ContentLevel) -> Self {
+        Self::Log {
+            content: content.as_ref().into(),
+            level,
+        }
+    }
+
+    /// Create log message backed by shared content with a given level
+    pub fn large_log<S: Into<Arc<str>>>(content: S, level: ContentLevel) -> Self {
+        Self::LargeLog {
+            content: content.into(),
+            level,
+        }
+    }
+
+    /// Create log message with info level
+    pub fn log_info<S: AsRef<str>>(content: S) -> Self {
+        Self::log(content, ContentLevel::Info)
+    }
+
+    /// Create log message with error level
+    pub fn log_error<S: AsRef<str>>(content: S) -> Self {
+        Self::log(content, ContentLevel::Error)
+    }
+
+    /// Create log message with warn level
+    pub fn log_warn<S: AsRef<str>>(content: S) -> Self {
+        Self::log(content, ContentLevel::Warn)
+    }
+
+    /// Create log message with trace level
+    pub fn log_trace<S: AsRef<str>>(content: S) -> Self {
+        Self::log(content, ContentLevel::Trace)
+    }
+
+    /// Create log message with debug level
+    pub fn log_debug<S: AsRef<str>>(content: S) -> Self {
+        Self::log(content, ContentLevel::Debug)
+    }
+
+    /// Get content level of the message, if it has one
+    pub fn level(&self) -> Option<&ContentLevel> {
+        match self {
+            Self::Notify { level, .. }
+            | Self::Log { level, .. }
+            | Self::LargeLog { level, .. }
+            | Self::UpdateCurrentTask { level, .. }
+            | Self::Diagnostic { level, .. } => Some(level),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the message has error level
+    pub fn is_error(&self) -> bool {
+        matches!(self.level(), Some(ContentLevel::Error))
+    }
+
+    /// Returns `true` if the message is [`Message::Notify`]
+    pub fn is_notify(&self) -> bool {
+        matches!(self, Self::Notify { .. })
+    }
+
+    /// Returns `true` if the message is [`Message::Log`] or [`Message::LargeLog`]
+    pub fn is_log(&self) -> bool {
+        matches!(self, Self::Log { .. } | Self::LargeLog { .. })
+    }
+}
+
 impl From<String> for Message {
     fn from(value: String) -> Self {
         Self::Notify {
@@ -110,3 +326,71 @@ impl From<&str> for Message {
         }
     }
 }
+
+#[test]
+fn test_log_constructors_level() {
+    let level = |message: Message| match message {
+        Message::Log { level, .. } => level,
+        message => panic!("Expected Log message, got {message:?}"),
+    };
+
+    assert_eq!(level(Message::log_info("x")), ContentLevel::Info);
+    assert_eq!(level(Message::log_error("x")), ContentLevel::Error);
+    assert_eq!(level(Message::log_warn("x")), ContentLevel::Warn);
+    assert_eq!(level(Message::log_trace("x")), ContentLevel::Trace);
+    assert_eq!(level(Message::log_debug("x")), ContentLevel::Debug);
+}
+
+#[test]
+fn test_log_constructors_content() {
+    let constructors: [fn(&str) -> Message; 5] = [
+        Message::log_info,
+        Message::log_error,
+        Message::log_warn,
+        Message::log_trace,
+        Message::log_debug,
+    ];
+
+    for constructor in constructors {
+        match constructor("Build Succeeded") {
+            Message::Log { content, .. } => assert_eq!(content, "Build Succeeded"),
+            message => panic!("Expected Log message, got {message:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_task_status_error_truncation() {
+    let cause = |status: TaskStatus| match status {
+        TaskStatus::Error(cause) => cause.as_str().to_string(),
+        status => panic!("Expected Error status, got {status:?}"),
+    };
+
+    assert_eq!(
+        cause(TaskStatus::error("  no such module 'Foo' ")),
+        "no such module 'Foo'"
+    );
+
+    let exact = "a".repeat(TaskStatus::MAX_ERROR_LEN);
+    assert_eq!(cause(TaskStatus::error(&exact)), exact);
+
+    let long = cause(TaskStatus::error("a".repeat(TaskStatus::MAX_ERROR_LEN + 1)));
+    assert_eq!(long.chars().count(), TaskStatus::MAX_ERROR_LEN);
+    assert!(long.ends_with('…'));
+
+    // NOTE: truncation must not split multi-byte characters
+    let multi_byte = cause(TaskStatus::error("é".repeat(TaskStatus::MAX_ERROR_LEN * 2)));
+    assert_eq!(multi_byte.chars().count(), TaskStatus::MAX_ERROR_LEN);
+    assert_eq!(multi_byte, "é".repeat(TaskStatus::MAX_ERROR_LEN - 1) + "…");
+}
+
+#[test]
+fn test_task_status_error_deserialize_truncated() {
+    let json = serde_json::json!({ "Error": "x".repeat(TaskStatus::MAX_ERROR_LEN * 2) });
+    let status = serde_json::from_value::<TaskStatus>(json).unwrap();
+
+    assert_eq!(
+        status,
+        TaskStatus::error("x".repeat(TaskStatus::MAX_ERROR_LEN * 2))
+    );
+}
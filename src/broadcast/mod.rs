@@ -16,13 +16,42 @@ use tokio::net::{UnixListener, UnixStream};
 use tokio::sync::{mpsc::*, Mutex, Notify};
 use tokio::task::JoinHandle;
 
+/// Path to a unix domain socket
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnixSocketPath(PathBuf);
+
+impl UnixSocketPath {
+    /// Whether the socket file exists
+    pub fn exists(&self) -> bool {
+        self.0.exists()
+    }
+}
+
+impl AsRef<Path> for UnixSocketPath {
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for UnixSocketPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.display())
+    }
+}
+
+impl From<UnixSocketPath> for PathBuf {
+    fn from(path: UnixSocketPath) -> Self {
+        path.0
+    }
+}
+
 /// Broadcast server to send task to clients
 #[derive(Debug)]
 pub struct Broadcast {
     /// Project root for which the logger is created for.
     root: PathBuf,
     /// Logger path
-    address: PathBuf,
+    address: UnixSocketPath,
     /// Logger handler
     pub handle: JoinHandle<()>,
     /// Server handler
@@ -34,6 +63,8 @@ pub struct Broadcast {
     /// Socket listeners
     #[allow(dead_code)]
     listeners: Arc<Mutex<HashMap<u32, UnixStream>>>,
+    /// File path to forward raw (possibly binary) output to
+    raw_output: std::sync::RwLock<PathBuf>,
 }
 
 impl Broadcast {
@@ -49,7 +80,7 @@ impl Broadcast {
             tokio::fs::create_dir(Self::ROOT).await?;
         }
 
-        let address = base.join(name);
+        let address = UnixSocketPath(base.join(name));
         let name = root.as_ref().name().unwrap();
 
         if address.exists() {
@@ -77,6 +108,7 @@ impl Broadcast {
 
         Ok(Self {
             root: root.as_ref().to_path_buf(),
+            raw_output: root.as_ref().join(".xbase").join("raw_output").into(),
             tx,
             abort,
             handle,
@@ -151,7 +183,16 @@ impl Broadcast {
                             continue;
                         }
 
-                        match serde_json::to_string(&message) {
+                        let value = match &message {
+                            Message::Batch(messages) => messages
+                                .iter()
+                                .map(serde_json::to_string)
+                                .collect::<serde_json::Result<Vec<_>>>()
+                                .map(|values| values.join("\n")),
+                            message => serde_json::to_string(message),
+                        };
+
+                        match value {
                             Ok(mut value) => {
                                 tracing::trace!("{value}");
                                 value.push('\n');
@@ -181,6 +222,11 @@ impl Broadcast {
         self.tx.send((id, message)).ok();
     }
 
+    /// Send multiple messages in a single write, so they are not interleaved with other messages
+    pub fn send_batch(&self, id: Option<u32>, messages: impl IntoIterator<Item = Message>) {
+        self.send(id, Message::Batch(messages.into_iter().collect()))
+    }
+
     /// Explicitly Abort/Consume logger
     pub fn abort(&self) {
         self.abort.notify_waiters();
@@ -194,17 +240,59 @@ impl Broadcast {
 
     /// Get a reference to the logger's log path.
     #[must_use]
-    pub fn address(&self) -> &PathBuf {
+    pub fn address(&self) -> &UnixSocketPath {
         &self.address
     }
+
+    /// Set file path raw output should be forwarded to, default `<root>/.xbase/raw_output`
+    pub fn set_raw_output<P: Into<PathBuf>>(&self, path: P) {
+        match self.raw_output.write() {
+            Ok(mut raw_output) => *raw_output = path.into(),
+            Err(err) => *err.into_inner() = path.into(),
+        }
+    }
+
+    /// Get file path raw output is forwarded to
+    pub fn raw_output(&self) -> PathBuf {
+        match self.raw_output.read() {
+            Ok(raw_output) => raw_output.clone(),
+            Err(err) => err.into_inner().clone(),
+        }
+    }
+
+    /// Append raw bytes (i.e. binary output) to raw output file without utf-8 conversion
+    pub async fn log_raw_bytes(&self, bytes: &[u8]) {
+        let path = self.raw_output();
+        let write = async {
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+            tokio::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .await?
+                .write_all(bytes)
+                .await
+        };
+
+        if let Err(err) = write.await {
+            tracing::error!("Failed to write raw output to {path:?}: {err}");
+        }
+    }
     /// Tell connected clients to open logger
     pub fn open_logger(&self) {
         self.send(None, Message::OpenLogger)
     }
 
-    /// Tell connected clients to reload language server
-    pub fn reload_lsp_server(&self) {
-        self.send(None, Message::ReloadLspServer)
+    /// Tell connected clients to clear logger
+    pub fn clear_logger(&self) {
+        self.send(None, Message::ClearLogger)
+    }
+
+    /// Tell connected clients to reload language servers, all of them if names is None
+    pub fn reload_lsp_server(&self, names: Option<Vec<String>>) {
+        self.send(None, Message::ReloadLspServer(names))
     }
 
     pub fn update_current_task<S: AsRef<str>>(&self, content: S, level: ContentLevel) {
@@ -230,34 +318,122 @@ impl Broadcast {
         )
     }
 
+    /// Tell connected clients to refresh their list of project targets
+    pub fn update_target_list(&self, targets: Vec<String>) {
+        self.send(None, Message::UpdateTargetList(targets))
+    }
+
+    /// Tell connected clients to update statusline state
+    pub fn update_statusline(&self, status: TaskStatus) {
+        self.send(None, Message::UpdateStatusline { status })
+    }
+
     pub fn set_state(&self, id: Option<u32>, state: State) {
         self.send(id, Message::SetState(state))
     }
 
-    /// Notify clients with a message
-    fn notify<S: AsRef<str>>(&self, msg: S, level: ContentLevel) {
-        let msg = msg.as_ref();
+    /// Tell connected clients to execute a shell command
+    pub fn execute_shell_command<S: AsRef<str>>(&self, command: S) {
+        self.send(None, Message::ExecuteShellCommand(command.as_ref().into()))
+    }
+
+    /// Tell connected clients to set a variable with the given value
+    pub fn set_variable<K: AsRef<str>, V: AsRef<str>>(&self, key: K, value: V) {
         self.send(
             None,
-            Message::Notify {
-                content: msg.to_string(),
+            Message::SetVariable {
+                key: key.as_ref().into(),
+                value: value.as_ref().into(),
+            },
+        )
+    }
+
+    /// Tell connected clients to open a given file
+    pub fn reveal_file<P: AsRef<Path>>(&self, path: P) {
+        self.send(None, Message::RevealFile(path.as_ref().to_path_buf()))
+    }
+
+    /// Tell connected clients to show a diagnostic at a given file line
+    pub fn diagnostic<P: AsRef<Path>, S: AsRef<str>>(
+        &self,
+        path: P,
+        line: u32,
+        msg: S,
+        level: ContentLevel,
+    ) {
+        self.send(
+            None,
+            Message::Diagnostic {
+                path: path.as_ref().display().to_string(),
+                line,
+                content: msg.as_ref().into(),
                 level,
             },
         )
     }
 
-    /// Log clients with a message
-    fn log<S: AsRef<str>>(&self, msg: S, level: ContentLevel) {
+    /// Tell connected clients to highlight a range in file until the next build
+    pub fn highlight_range<P: AsRef<Path>, S: AsRef<str>>(
+        &self,
+        file: P,
+        (start_line, start_col): (u32, u32),
+        (end_line, end_col): (u32, u32),
+        group: S,
+    ) {
+        self.send(
+            None,
+            Message::HighlightRange {
+                file: file.as_ref().to_path_buf(),
+                start_line,
+                start_col,
+                end_line,
+                end_col,
+                group: group.as_ref().into(),
+            },
+        )
+    }
+
+    /// Notify clients with a message
+    fn notify<S: AsRef<str>>(&self, msg: S, level: ContentLevel) {
         let msg = msg.as_ref();
         self.send(
             None,
-            Message::Log {
+            Message::Notify {
                 content: msg.to_string(),
                 level,
             },
         )
     }
 
+    /// Log clients with a message
+    fn log<S: AsRef<str>>(&self, msg: S, level: ContentLevel) {
+        self.send(None, Message::log(msg, level))
+    }
+
+    /// Log process output to clients, without copying large output lines per client
+    pub fn log_process_item(&self, item: ProcessItem) {
+        let message = match item {
+            ProcessItem::Output(content) => Message::large_log(content, ContentLevel::Info),
+            ProcessItem::Error(content) => {
+                Self::trace_with_level(ContentLevel::Error, &content);
+                Message::large_log(content, ContentLevel::Error)
+            }
+            ProcessItem::Exit(_) => return,
+        };
+        self.send(None, message)
+    }
+
+    /// Emit tracing event with the equivalent level of a given content level
+    fn trace_with_level(level: ContentLevel, msg: &str) {
+        match tracing::Level::from(level) {
+            tracing::Level::TRACE => tracing::trace!("{msg}"),
+            tracing::Level::DEBUG => tracing::debug!("{msg}"),
+            tracing::Level::INFO => tracing::info!("{msg}"),
+            tracing::Level::WARN => tracing::warn!("{msg}"),
+            _ => tracing::error!("{msg}"),
+        }
+    }
+
     /// Notify client with a message and id
     fn notify_with_id<S: AsRef<str>>(&self, msg: S, id: u32, level: ContentLevel) {
         let msg = msg.as_ref();
@@ -272,14 +448,7 @@ impl Broadcast {
 
     /// Log client with a message and id
     fn log_with_id<S: AsRef<str>>(&self, msg: S, id: u32, level: ContentLevel) {
-        let msg = msg.as_ref();
-        self.send(
-            Some(id),
-            Message::Log {
-                content: msg.to_string(),
-                level,
-            },
-        )
+        self.send(Some(id), Message::log(msg, level))
     }
 
     /// Notify clients with a message
@@ -296,7 +465,8 @@ impl Broadcast {
     /// Notify clients with a warn message
     pub fn warn<S: AsRef<str>>(&self, msg: S) {
         tracing::warn!("{}", msg.as_ref());
-        self.notify(msg, ContentLevel::Warn)
+        self.notify(msg, ContentLevel::Warn);
+        self.update_statusline(TaskStatus::Warning)
     }
 
     /// Notify clients with a trace message
@@ -340,6 +510,19 @@ impl Broadcast {
         self.log(msg, ContentLevel::Debug)
     }
 
+    /// Log a separator line to clients
+    pub fn log_separator(&self) {
+        self.log_separator_custom('.', 73)
+    }
+
+    /// Log a separator line made of a given character and length to clients
+    pub fn log_separator_custom(&self, ch: char, length: usize) {
+        self.log(
+            crate::util::fmt::separator_with(ch, length),
+            ContentLevel::Info,
+        )
+    }
+
     /// Notify a specific client with a message
     pub fn info_with_id<S: AsRef<str>>(&self, id: u32, msg: S) {
         self.notify_with_id(msg, id, ContentLevel::Info)
@@ -1,12 +1,16 @@
 use super::*;
+use std::time::Duration;
 
 #[derive(Debug, Clone)]
 pub struct Task {
     #[allow(dead_code)]
     task: TaskKind,
-    #[allow(dead_code)]
     target: String,
     inner: Arc<Broadcast>,
+    /// Time after which the consumed process gets killed and the task fails
+    timeout: Option<Duration>,
+    /// Time after which clients get warned that the task takes longer than expected
+    estimate: Option<Duration>,
 }
 
 impl Task {
@@ -27,9 +31,23 @@ impl Task {
             task,
             target: target.into(),
             inner: broadcast,
+            timeout: None,
+            estimate: None,
         }
     }
 
+    /// Fail task and kill consumed process if it doesn't finish within timeout
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Warn once, without killing consumed process, if it doesn't finish within estimate
+    pub fn with_estimate(mut self, estimate: Duration) -> Self {
+        self.estimate = Some(estimate);
+        self
+    }
+
     fn update<S: AsRef<str>>(&self, level: ContentLevel, content: S) {
         let content = content.as_ref().into();
         let message = Message::UpdateCurrentTask { content, level };
@@ -62,18 +80,17 @@ impl Task {
 
     /// Finish task with whether it was successfull or not
     pub fn finish(&self, success: bool) {
+        let status = if success {
+            TaskStatus::Succeeded
+        } else {
+            TaskStatus::Failed
+        };
+
+        tracing::debug!("[{}] {status}", self.target);
+
         self.inner
             .tx
-            .send((
-                None,
-                Message::FinishCurrentTask {
-                    status: if success {
-                        TaskStatus::Succeeded
-                    } else {
-                        TaskStatus::Failed
-                    },
-                },
-            ))
+            .send((None, Message::FinishCurrentTask { status }))
             .ok();
 
         if !success {
@@ -81,33 +98,78 @@ impl Task {
         }
     }
 
-    pub fn consume(&self, mut process: Box<dyn ProcessExt + Send>) -> Result<Receiver<bool>> {
+    /// Consume process output, returning the task handle and a receiver for whether it succeeded.
+    ///
+    /// Aborting the handle drops the status sender, closing the receiver.
+    pub fn consume(
+        &self,
+        mut process: Box<dyn ProcessExt + Send>,
+    ) -> Result<(JoinHandle<()>, Receiver<bool>)> {
         let mut stream = process.spawn_and_stream()?;
         let cancel = self.inner.abort.clone();
         let abort = process.aborter().unwrap();
         let this = self.clone();
         let (send_status, recv_status) = channel(1);
+        let deadline = self
+            .timeout
+            .map(|timeout| tokio::time::Instant::now() + timeout);
+        let mut estimate_deadline = self
+            .estimate
+            .map(|estimate| tokio::time::Instant::now() + estimate);
 
-        tokio::spawn(async move {
+        let handle = tokio::spawn(async move {
+            // NOTE: last error line is used as failure cause
+            let mut last_error = None;
             loop {
                 let send_status = send_status.clone();
+                let timeout = async {
+                    match deadline {
+                        Some(deadline) => tokio::time::sleep_until(deadline).await,
+                        None => std::future::pending().await,
+                    }
+                };
+                let estimate = async move {
+                    match estimate_deadline {
+                        Some(deadline) => tokio::time::sleep_until(deadline).await,
+                        None => std::future::pending().await,
+                    }
+                };
                 tokio::select! {
                     _ = cancel.notified() => {
                         abort.notify_one();
                         send_status.send(false).await.unwrap_or_default();
                         break;
                     },
+                    _ = timeout => {
+                        abort.notify_one();
+                        let secs = this.timeout.unwrap_or_default().as_secs();
+                        this.error(format!("[{}] Timed out after {secs}s", this.target));
+                        this.finish(false);
+                        this.inner.update_statusline(TaskStatus::error(format!("Timed out after {secs}s")));
+                        send_status.send(false).await.ok();
+                        break;
+                    },
+                    _ = estimate => {
+                        estimate_deadline = None;
+                        let secs = this.estimate.unwrap_or_default().as_secs();
+                        this.warn(format!("[{}] Taking longer than estimated {secs}s", this.target));
+                    },
                     result = stream.next() => match result {
                         Some(output) => {
                             if let Some(succ) = output.is_success() {
                                 this.finish(succ);
+                                if let (false, Some(cause)) = (succ, &last_error) {
+                                    this.inner.update_statusline(TaskStatus::error(cause));
+                                }
                                 send_status.send(succ).await.ok();
                                 break;
                             } else if let ProcessItem::Error(content) = output {
-                                this.error(content)
+                                this.error(&content);
+                                last_error = Some(content);
                             } else if let ProcessItem::Output(content) = output {
                                 if content.to_lowercase().contains("error") {
-                                    this.error(content)
+                                    this.error(&content);
+                                    last_error = Some(content);
                                 } else if content.to_lowercase().contains("warn") {
                                     this.warn(content)
                                 } else {
@@ -122,7 +184,7 @@ impl Task {
                 };
             }
         });
-        Ok(recv_status)
+        Ok((handle, recv_status))
     }
 
     pub fn inner(&self) -> &Broadcast {
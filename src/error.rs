@@ -42,6 +42,22 @@ pub enum Error {
     MessageParse(String),
     #[error("{0} is not a registered project!")]
     UnknownProject(PathBuf),
+    #[error("Failed to watch project: {0}")]
+    Watch(String),
+    #[error("{0} is not supported on this platform")]
+    Unsupported(String),
+    #[error("Maximum number of registered projects reached")]
+    ProjectLimitReached,
+    #[error(
+        "Package requires swift-tools-version {required} but installed toolchain is {installed}"
+    )]
+    ToolchainTooOld { required: String, installed: String },
+    #[error("Invalid ignore pattern `{0}`: {1}")]
+    InvalidIgnorePattern(String, String),
+    #[error("Failed to code sign: {0}")]
+    CodeSign(String),
+    #[error("`{0}` is already being watched")]
+    DuplicateWatchable(String),
 }
 
 impl From<ServerError> for Error {
@@ -56,6 +72,36 @@ impl From<ServerError> for Error {
             "DefinitionMutliFound" => Self::DefinitionMutliFound,
             "SendError" => Self::SendError(v.msg),
             "MessageParse" => Self::MessageParse(v.msg),
+            "Watch" => Self::Watch(v.msg),
+            "Unsupported" => Self::Unsupported(v.msg),
+            "ProjectLimitReached" => Self::ProjectLimitReached,
+            "ToolchainTooOld" => {
+                // NOTE: only the message is sent, so fields are read back from it
+                let versions = v
+                    .msg
+                    .strip_prefix("Package requires swift-tools-version ")
+                    .and_then(|rest| rest.split_once(" but installed toolchain is "));
+                let (required, installed) = match versions {
+                    Some((required, installed)) => (required.into(), installed.into()),
+                    None => (v.msg, "".into()),
+                };
+                Self::ToolchainTooOld {
+                    required,
+                    installed,
+                }
+            }
+            "InvalidIgnorePattern" => {
+                let pattern = v
+                    .msg
+                    .strip_prefix("Invalid ignore pattern `")
+                    .and_then(|rest| rest.split_once("`: "));
+                match pattern {
+                    Some((pattern, err)) => Self::InvalidIgnorePattern(pattern.into(), err.into()),
+                    None => Self::InvalidIgnorePattern("".into(), v.msg),
+                }
+            }
+            "CodeSign" => Self::CodeSign(v.msg),
+            "DuplicateWatchable" => Self::DuplicateWatchable(v.msg),
             _ => Self::Unexpected(v.msg),
         }
     }
@@ -82,6 +128,13 @@ impl From<&Error> for ServerError {
             Error::MessageParse(_) => res.kind = "MessageParse".into(),
             Error::Compile => res.kind = "Compile".into(),
             Error::UnknownProject(_) => res.kind = "UnknownProject".into(),
+            Error::Watch(_) => res.kind = "Watch".into(),
+            Error::Unsupported(_) => res.kind = "Unsupported".into(),
+            Error::ProjectLimitReached => res.kind = "ProjectLimitReached".into(),
+            Error::ToolchainTooOld { .. } => res.kind = "ToolchainTooOld".into(),
+            Error::InvalidIgnorePattern(_, _) => res.kind = "InvalidIgnorePattern".into(),
+            Error::CodeSign(_) => res.kind = "CodeSign".into(),
+            Error::DuplicateWatchable(_) => res.kind = "DuplicateWatchable".into(),
         };
         res
     }
@@ -188,3 +241,22 @@ impl<T: std::fmt::Debug> From<tokio::sync::mpsc::error::SendError<T>> for Error
         Self::SendError(format!("Channel closed, unable to send `{:?}`", v))
     }
 }
+
+#[test]
+fn test_server_error_round_trip() {
+    let errors = [
+        Error::ToolchainTooOld {
+            required: "5.9".into(),
+            installed: "5.7".into(),
+        },
+        Error::InvalidIgnorePattern("**/[".into(), "unclosed class".into()),
+    ];
+    for error in errors {
+        let round_trip = Error::from(ServerError::from(&error));
+        assert_eq!(round_trip.to_string(), error.to_string());
+        assert_eq!(
+            ServerError::from(&round_trip).kind,
+            ServerError::from(&error).kind
+        );
+    }
+}
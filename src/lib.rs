@@ -10,7 +10,7 @@ mod watcher;
 
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
-use std::{path::PathBuf, sync::Arc};
+use std::{path::PathBuf, sync::Arc, time::Duration};
 use tokio::sync::{Mutex, OwnedMutexGuard};
 
 pub use {
@@ -22,6 +22,24 @@ pub static PID_PATH: &str = "/tmp/xbase.pid";
 pub static LOG_PATH: &str = "/tmp/xbase.log";
 pub static BIN_ROOT: &str = "$HOME/.local/share/xbase";
 
+/// Maximum number of simultaneously registered projects, overridable with `XBASE_MAX_PROJECTS`
+pub static MAX_PROJECTS: Lazy<usize> = Lazy::new(|| {
+    std::env::var("XBASE_MAX_PROJECTS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(50)
+});
+
+/// Minimum quiet period before events of a path are processed again, overridable with
+/// `XBASE_WATCH_DEBOUNCE_MS` (`0` disables debouncing)
+pub static WATCH_DEBOUNCE: Lazy<Duration> = Lazy::new(|| {
+    std::env::var("XBASE_WATCH_DEBOUNCE_MS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .map(Duration::from_millis)
+        .unwrap_or(Duration::from_millis(100))
+});
+
 pub type ProjectRuntimes = HashMap<PathBuf, PRMessageSender>;
 
 static RUNTIMES: Lazy<Arc<Mutex<ProjectRuntimes>>> = Lazy::new(Default::default);
@@ -34,3 +52,10 @@ pub async fn runtimes() -> OwnedMutexGuard<ProjectRuntimes> {
     tracing::trace!("Returning");
     x
 }
+
+/// Send a message to clients of every registered project
+pub async fn broadcast_to_all(message: Message) {
+    for runtime in runtimes().await.values() {
+        runtime.send(PRMessage::Broadcast(message.clone()));
+    }
+}
@@ -15,7 +15,14 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
     let os_signal_handler = tokio::spawn(handle_os_signals());
 
     let listener = {
-        tracing_setup(LOG_PATH, tracing::Level::DEBUG, true)?;
+        tracing_setup(
+            LOG_PATH,
+            tracing::Level::DEBUG,
+            true,
+            Default::default(),
+            Default::default(),
+            None,
+        )?;
         cleanup_daemon_runtime(PID_PATH, SOCK_ADDR).await?;
         write(PID_PATH, std::process::id().to_string()).await?;
         UnixListener::bind(SOCK_ADDR).unwrap()
@@ -18,6 +18,7 @@ pub struct BareboneProject {
     xcodeproj: XCodeProject,
 }
 
+#[async_trait::async_trait]
 impl ProjectData for BareboneProject {
     fn root(&self) -> &PathBuf {
         &self.root
@@ -42,11 +43,18 @@ impl ProjectData for BareboneProject {
     fn watchignore(&self) -> &Vec<String> {
         &self.watchignore
     }
+
+    fn watchignore_mut(&mut self) -> &mut Vec<String> {
+        &mut self.watchignore
+    }
 }
 
 #[async_trait::async_trait]
 impl ProjectBuild for BareboneProject {}
 
+#[async_trait::async_trait]
+impl ProjectTest for BareboneProject {}
+
 #[async_trait::async_trait]
 impl ProjectRun for BareboneProject {}
 
@@ -65,7 +73,7 @@ impl ProjectCompile for BareboneProject {
 
         let xcworkspace = format!("{name}.xcworkspace");
 
-        if self.root().join(&xcworkspace).exists() {
+        if self.is_workspace() {
             for scheme in self.xcodeproj.schemes().iter() {
                 let mut args = args.clone();
                 args.extend_from_slice(&[
@@ -76,13 +84,13 @@ impl ProjectCompile for BareboneProject {
                 ]);
                 let xclogger = XCLogger::new(&root, &args)?;
                 xccommands.push(xclogger.compile_commands.clone());
-                tasks_recvs.push(task.consume(Box::new(xclogger))?);
+                tasks_recvs.push(task.consume(Box::new(xclogger))?.1);
             }
         } else {
             args.extend_from_slice(&["-project".into(), format!("{name}.xcodeproj")]);
             let xclogger = XCLogger::new(&root, &args)?;
             xccommands.push(xclogger.compile_commands.clone());
-            tasks_recvs.push(task.consume(Box::new(xclogger))?);
+            tasks_recvs.push(task.consume(Box::new(xclogger))?.1);
         }
 
         let _all_pass = tasks_recvs
@@ -106,7 +114,7 @@ impl ProjectCompile for BareboneProject {
         xccommands.dedup();
 
         let json = serde_json::to_vec_pretty(&xccommands)?;
-        tokio::fs::write(root.join(".compile"), &json).await?;
+        tokio::fs::write(self.compile_database_path(), &json).await?;
 
         Ok(())
     }
@@ -156,6 +164,7 @@ impl Project for BareboneProject {
             .into_iter()
             .map(|(k, info)| (k, info.into()))
             .collect();
+        update_xcode_targets_info(&xcodeproj_paths[0], &mut project.targets).await;
 
         tracing::info!("targets: {:?}", project.targets());
         Ok(project)
@@ -7,12 +7,15 @@ use crate::util::PathExt;
 use crate::*;
 use anyhow::Context;
 use once_cell::sync::Lazy;
+use process_stream::ProcessExt;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use xclog::{XCBuildSettings, XCLogger};
 
 /// Project Data
+#[async_trait::async_trait]
 pub trait ProjectData: std::fmt::Debug {
     /// Project root
     fn root(&self) -> &PathBuf;
@@ -36,6 +39,72 @@ pub trait ProjectData: std::fmt::Debug {
     }
     /// Get Ignore patterns
     fn watchignore(&self) -> &Vec<String>;
+    /// Get mut Ignore patterns
+    fn watchignore_mut(&mut self) -> &mut Vec<String>;
+    /// Configuration names across all project targets, deduplicated and sorted
+    fn configuration_names(&self) -> Vec<&str> {
+        let mut names = self
+            .targets()
+            .values()
+            .flat_map(|info| info.configurations.iter().map(String::as_str))
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        names.dedup();
+        names
+    }
+    /// Whether project is built as part of `<name>.xcworkspace` in project root
+    fn is_workspace(&self) -> bool {
+        self.root()
+            .join(format!("{}.xcworkspace", self.name()))
+            .exists()
+    }
+    /// Xcode project or workspace found next to a project of different kind
+    fn adjacent_xcode_project(&self) -> Option<&PathBuf> {
+        None
+    }
+    /// Directories outside of project root to watch as well (e.g. local swift packages)
+    fn watch_paths(&self) -> Vec<PathBuf> {
+        vec![]
+    }
+    /// Map of project targets to the targets they depend on.
+    ///
+    /// By default, edges are read from [`TargetInfo::dependencies`].
+    fn target_dependency_graph(&self) -> HashMap<String, Vec<String>> {
+        self.targets()
+            .iter()
+            .map(|(name, info)| (name.clone(), info.dependencies.clone()))
+            .collect()
+    }
+    /// Project targets ordered so that each target comes after its dependencies.
+    ///
+    /// Targets part of a dependency cycle are appended at the end.
+    fn topological_order(&self) -> Vec<String> {
+        topological_order(&self.target_dependency_graph())
+    }
+    /// Whether the project git working tree is dirty, cached for 5 seconds
+    async fn has_uncommitted_changes(&self) -> bool {
+        static CACHE: Lazy<std::sync::Mutex<HashMap<PathBuf, (Instant, bool)>>> =
+            Lazy::new(Default::default);
+        fn cache() -> std::sync::MutexGuard<'static, HashMap<PathBuf, (Instant, bool)>> {
+            match CACHE.lock() {
+                Ok(cache) => cache,
+                Err(err) => err.into_inner(),
+            }
+        }
+
+        let root = self.root();
+        if let Some((checked_at, dirty)) = cache().get(root) {
+            if checked_at.elapsed() < Duration::from_secs(5) {
+                return *dirty;
+            }
+        }
+
+        let dirty = fs::git_has_uncommitted_changes(root, Duration::from_secs(2))
+            .await
+            .unwrap_or_default();
+        cache().insert(root.clone(), (Instant::now(), dirty));
+        dirty
+    }
     /// read dir and get xcodeproj paths
     fn get_xcodeproj_paths(&self) -> Result<Vec<PathBuf>> {
         Ok(wax::walk("*.xcodeproj", &self.root())
@@ -55,11 +124,211 @@ pub trait ProjectBuild: ProjectData {
         device: Option<&Device>,
         broadcast: &Arc<Broadcast>,
     ) -> Result<(Vec<String>, tokio::sync::mpsc::Receiver<bool>)> {
-        let mut args = cfg.to_args();
+        let args = self.build_arguments(cfg, device)?;
         let target = &cfg.target;
+        let mut task = Task::new(TaskKind::Build, target, broadcast.clone());
+        if let Some(info) = self.targets().get(target) {
+            task = task.with_estimate(cfg.estimated_build_time(info));
+        }
+        if let Some(timeout) = cfg.timeout() {
+            task = task.with_timeout(timeout);
+        }
+
+        let mut env_vars = cfg.redacted_env_vars();
+        env_vars.extend(args.iter().cloned());
+        task.debug(format!("[{target}] {}", env_vars.join(" ")));
+
+        let mut logger = XCLogger::new(self.root(), &args)?;
+        ProcessExt::get_command(&mut logger).envs(&cfg.env_vars);
+
+        let (_, recv) = task.consume(Box::new(logger))?;
+        let output_dir = fs::get_build_cache_dir_with_config(self.root(), cfg)?;
+        let recv = self.with_post_build_hook(target, output_dir.into(), recv, broadcast);
+
+        Ok((args, recv))
+    }
+
+    /// Command to run after a successful build, by default read from `XBASE_POST_BUILD_HOOK`.
+    ///
+    /// The build output directory is passed to the command as `XBASE_BUILD_OUTPUT_DIR`.
+    fn post_build_hook(&self) -> Option<Vec<String>> {
+        let hook = std::env::var("XBASE_POST_BUILD_HOOK").ok()?;
+        shell_words::split(&hook)
+            .ok()
+            .filter(|args| !args.is_empty())
+    }
+
+    /// Run post_build_hook once build result is received, returning a receiver for the final result
+    fn with_post_build_hook(
+        &self,
+        target: &str,
+        output_dir: PathBuf,
+        mut recv: tokio::sync::mpsc::Receiver<bool>,
+        broadcast: &Arc<Broadcast>,
+    ) -> tokio::sync::mpsc::Receiver<bool> {
+        let hook = match self.post_build_hook() {
+            Some(hook) => hook,
+            None => return recv,
+        };
+        let (tx, rx) = tokio::sync::mpsc::channel(1);
+        let root = self.root().clone();
+        let target = target.to_string();
+        let broadcast = broadcast.clone();
+
+        tokio::spawn(async move {
+            let mut success = recv.recv().await.unwrap_or_default();
+            if success {
+                broadcast.info(format!("[{target}] Running post build hook"));
+                let output = tokio::process::Command::new(&hook[0])
+                    .args(&hook[1..])
+                    .env("XBASE_BUILD_OUTPUT_DIR", &output_dir)
+                    .current_dir(root)
+                    .output()
+                    .await;
+                match output {
+                    Ok(output) if output.status.success() => {}
+                    Ok(output) => {
+                        let stderr = String::from_utf8_lossy(&output.stderr);
+                        broadcast.error(format!("[{target}] Post build hook failed {stderr}"));
+                        success = false;
+                    }
+                    Err(err) => {
+                        broadcast.error(format!("[{target}] Fail to run post build hook {err}"));
+                        success = false;
+                    }
+                }
+            }
+            tx.send(success).await.ok();
+        });
+
+        rx
+    }
+
+    /// Build Project by scheme, which may include multiple targets and their tests
+    fn build_scheme(
+        &self,
+        cfg: &BuildSettings,
+        device: Option<&Device>,
+        broadcast: &Arc<Broadcast>,
+    ) -> Result<(Vec<String>, tokio::sync::mpsc::Receiver<bool>)> {
+        let scheme = cfg
+            .scheme
+            .as_ref()
+            .ok_or_else(|| Error::Build(format!("No scheme provided for {}", cfg.target)))?;
+
+        // NOTE: target is empty unless clients explicitly set both
+        if !cfg.target.is_empty() {
+            broadcast.warn(format!(
+                "Both target `{}` and scheme `{scheme}` are set, building scheme `{scheme}`",
+                cfg.target
+            ));
+        }
+
+        let cfg = BuildSettings {
+            target: scheme.clone(),
+            ..cfg.clone()
+        };
+
+        self.build(&cfg, device, broadcast)
+    }
+
+    /// Build project, then rebuild it on filesystem changes until returned handle is cancelled.
+    ///
+    /// req is added to subscribers, so watchignore and debouncing apply.
+    fn watch_build(
+        &self,
+        req: crate::server::BuildRequest,
+        broadcast: &Arc<Broadcast>,
+        subscribers: &mut WatchSubscribers,
+    ) -> Result<WatchBuildHandle> {
+        if req.settings.scheme.is_some() {
+            self.build_scheme(&req.settings, None, broadcast)?;
+        } else {
+            self.build(&req.settings, None, broadcast)?;
+        }
+        let handle = WatchBuildHandle {
+            key: req.to_string(),
+        };
+        subscribers.add(req)?;
+        Ok(handle)
+    }
+
+    /// Bundle multiple platform `.framework` slices into a single `.xcframework` at output
+    fn build_xcframework(
+        &self,
+        frameworks: Vec<(Platform, PathBuf)>,
+        output: &Path,
+        broadcast: &Arc<Broadcast>,
+    ) -> Result<tokio::sync::mpsc::Receiver<bool>> {
+        let name = output.name().unwrap_or_else(|| self.name().to_string());
+        let task = Task::new(TaskKind::Build, &name, broadcast.clone());
+        let mut args = vec!["xcodebuild".to_string(), "-create-xcframework".into()];
+
+        for (platform, framework) in frameworks.iter() {
+            task.debug(format!("[{name}] {platform}: {}", framework.display()));
+            args.extend_from_slice(&["-framework".into(), framework.display().to_string()]);
+        }
+
+        args.extend_from_slice(&["-output".into(), output.display().to_string()]);
+        task.debug(format!("[{name}] {}", args.join(" ")));
+
+        let mut process = process_stream::Process::new("/usr/bin/xcrun");
+        process.args(&args);
+        process.current_dir(self.root());
+
+        let (_, recv) = task.consume(Box::new(process))?;
+
+        Ok(recv)
+    }
+
+    /// Build Project with Instruments compatible flags and dSYMs
+    ///
+    /// On success, the dSYM folder is set to `xbase_dsym_path` client variable.
+    fn build_for_profiling(
+        &self,
+        cfg: &BuildSettings,
+        broadcast: &Arc<Broadcast>,
+    ) -> Result<tokio::sync::mpsc::Receiver<bool>> {
+        let mut args = self.build_arguments(cfg, None)?;
+        let target = &cfg.target;
+        let dsym_root = format!(
+            "{}/dSYMs",
+            fs::get_build_cache_dir_with_config(self.root(), cfg)?
+        );
+        let task = Task::new(TaskKind::Profile, target, broadcast.clone());
+
+        args.extend_from_slice(&[
+            "DEBUG_INFORMATION_FORMAT=dwarf-with-dsym".into(),
+            "GCC_OPTIMIZATION_LEVEL=0".into(),
+            format!("DWARF_DSYM_FOLDER_PATH={dsym_root}"),
+        ]);
+
+        let mut env_vars = cfg.redacted_env_vars();
+        env_vars.extend(args.iter().cloned());
+        task.debug(format!("[{target}] {}", env_vars.join(" ")));
+
+        let mut logger = XCLogger::new(self.root(), &args)?;
+        ProcessExt::get_command(&mut logger).envs(&cfg.env_vars);
+
+        let (_, mut recv) = task.consume(Box::new(logger))?;
+        let (tx, rx) = tokio::sync::mpsc::channel(1);
+        let broadcast = broadcast.clone();
+
+        tokio::spawn(async move {
+            let success = recv.recv().await.unwrap_or_default();
+            if success {
+                broadcast.set_variable("xbase_dsym_path", dsym_root);
+            }
+            tx.send(success).await.ok();
+        });
+
+        Ok(rx)
+    }
+
+    /// Get xcodebuild arguments to build project using BuildSettings and optionally a device
+    fn build_arguments(&self, cfg: &BuildSettings, device: Option<&Device>) -> Result<Vec<String>> {
+        let mut args = cfg.to_args();
         let name = self.name().to_owned();
-        let xcworkspace = format!("{}.xcworkspace", &name);
-        let task = Task::new(TaskKind::Build, target, broadcast.clone());
 
         args.insert(0, "build".to_string());
 
@@ -72,22 +341,95 @@ pub trait ProjectBuild: ProjectData {
         args.push(format!("SYMROOT={cache_build_root}",));
         args.push("-allowProvisioningUpdates".into());
 
-        if self.root().join(&xcworkspace).exists() {
+        if self.is_workspace() {
             args.iter_mut().for_each(|arg| {
                 if arg == "-target" {
                     *arg = "-scheme".into()
                 }
             });
-            args.extend_from_slice(&["-workspace".into(), xcworkspace]);
+            args.extend_from_slice(&["-workspace".into(), format!("{name}.xcworkspace")]);
         } else {
             args.extend_from_slice(&["-project".into(), format!("{}.xcodeproj", name)]);
         }
 
-        task.debug(format!("[{target}] {}", args.join(" ")));
+        Ok(args)
+    }
 
-        let recv = task.consume(Box::new(XCLogger::new(self.root(), &args)?))?;
+    /// Build all (target, configuration) combinations of the project, one after another
+    ///
+    /// NOTE: concurrent xcodebuild processes fail to lock the shared build database
+    async fn build_matrix(
+        &self,
+        broadcast: &Arc<Broadcast>,
+    ) -> Result<HashMap<(String, String), tokio::sync::mpsc::Receiver<bool>>>
+    where
+        Self: Sync,
+    {
+        let mut matrix = HashMap::default();
 
-        Ok((args, recv))
+        for (target, info) in self.targets().iter() {
+            for configuration in info.configurations.iter() {
+                let cfg = BuildSettings {
+                    target: target.clone(),
+                    configuration: configuration.clone(),
+                    scheme: None,
+                    launch_args: vec![],
+                    env: Default::default(),
+                    signing_identity: None,
+                    env_vars: Default::default(),
+                    timeout: None,
+                };
+                let (_, mut recv) = self.build(&cfg, None, broadcast)?;
+                let success = recv.recv().await.unwrap_or_default();
+                let (tx, recv) = tokio::sync::mpsc::channel(1);
+                tx.send(success).await.ok();
+                matrix.insert((target.clone(), configuration.clone()), recv);
+            }
+        }
+
+        Ok(matrix)
+    }
+
+    /// Sign bundle or binary at path with a given signing identity
+    async fn code_sign(&self, identity: &str, path: &Path, broadcast: &Arc<Broadcast>) -> Result<()>
+    where
+        Self: Sync,
+    {
+        let name = path.name().unwrap_or_else(|| self.name().to_string());
+        let task = Task::new(TaskKind::Build, &name, broadcast.clone());
+
+        task.info(format!("[{name}] Signing with `{identity}`"));
+
+        let output = tokio::process::Command::new("codesign")
+            .args(["--sign", identity, "--deep"])
+            .arg(path)
+            .current_dir(self.root())
+            .output()
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            task.error(format!("[{name}] {stderr}"));
+            task.finish(false);
+            return Err(Error::CodeSign(stderr));
+        }
+
+        task.finish(true);
+        Ok(())
+    }
+
+    /// Build DocC documentation for a given target
+    ///
+    /// Once built, documentation root url is set to `xbase_docs_url` client variable.
+    fn build_documentation(
+        &self,
+        target: &str,
+        _broadcast: &Arc<Broadcast>,
+    ) -> Result<tokio::sync::mpsc::Receiver<bool>> {
+        Err(Error::Unsupported(format!(
+            "[{target}] Building documentation for {}",
+            self.name()
+        )))
     }
 
     /// Get build cache root
@@ -98,6 +440,49 @@ pub trait ProjectBuild: ProjectData {
     }
 }
 
+/// Handle to a build started with [`ProjectBuild::watch_build`]
+#[derive(Debug)]
+pub struct WatchBuildHandle {
+    key: String,
+}
+
+impl WatchBuildHandle {
+    /// Watch subscriber key of the build
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Stop rebuilding on filesystem changes
+    pub async fn cancel(self, subscribers: &mut WatchSubscribers) {
+        subscribers.remove(&self.key).await;
+    }
+}
+
+#[async_trait::async_trait]
+pub trait ProjectTest: ProjectData + ProjectBuild {
+    /// Test Project using BuildSettings and optionally a device
+    fn test(
+        &self,
+        cfg: &BuildSettings,
+        device: Option<&Device>,
+        broadcast: &Arc<Broadcast>,
+    ) -> Result<(Vec<String>, tokio::sync::mpsc::Receiver<bool>)> {
+        let mut args = self.build_arguments(cfg, device)?;
+        let target = &cfg.target;
+        let task = Task::new(TaskKind::Test, target, broadcast.clone());
+
+        args[0] = "test".into();
+        task.debug(format!("[{target}] {}", args.join(" ")));
+
+        let mut logger = XCLogger::new(self.root(), &args)?;
+        ProcessExt::get_command(&mut logger).envs(&cfg.env_vars);
+
+        let (_, recv) = task.consume(Box::new(logger))?;
+
+        Ok((args, recv))
+    }
+}
+
 #[async_trait::async_trait]
 pub trait ProjectRun: ProjectData + ProjectBuild {
     fn get_runner(
@@ -113,14 +498,22 @@ pub trait ProjectRun: ProjectData + ProjectBuild {
         let (args, recv) = self.build(cfg, device, broadcast)?;
 
         let info = XCBuildSettings::new_sync(self.root(), &args)?;
+        let launch_args = self.get_launch_arguments(cfg);
 
         let runner: Box<dyn Runner + Send + Sync> = match device {
-            Some(device) => Box::new(SimulatorRunner::new(device.clone(), &info)),
-            None => Box::new(BinRunner::from_build_info(&info)),
+            Some(device) => {
+                Box::new(SimulatorRunner::new(device.clone(), &info).with_launch_args(launch_args))
+            }
+            None => Box::new(BinRunner::from_build_info(&info).with_launch_args(launch_args)),
         };
 
         Ok((runner, args, recv))
     }
+
+    /// Get arguments to pass to the running application
+    fn get_launch_arguments(&self, cfg: &BuildSettings) -> Vec<String> {
+        cfg.launch_args.clone()
+    }
 }
 
 #[async_trait::async_trait]
@@ -128,6 +521,14 @@ pub trait ProjectCompile: ProjectData {
     /// Generate compile database in project root
     async fn update_compile_database(&self, broadcast: &Arc<Broadcast>) -> Result<()>;
 
+    /// Path to compile database, overridable with `XBASE_COMPILE_DATABASE` (relative to root).
+    fn compile_database_path(&self) -> PathBuf {
+        match std::env::var("XBASE_COMPILE_DATABASE") {
+            Ok(path) if !path.is_empty() => self.root().join(path),
+            _ => self.root().join("compile_commands.json"),
+        }
+    }
+
     /// Get compile arguments
     fn compile_arguments(&self) -> Vec<String> {
         vec![
@@ -159,13 +560,82 @@ pub trait ProjectGenerate: ProjectData {
 #[async_trait::async_trait]
 /// Project Extension that can be built, ran and regenerated
 pub trait Project:
-    ProjectData + ProjectBuild + ProjectRun + ProjectCompile + ProjectGenerate + Sync + Send
+    ProjectData
+    + ProjectBuild
+    + ProjectTest
+    + ProjectRun
+    + ProjectCompile
+    + ProjectGenerate
+    + Sync
+    + Send
 {
     /// Create new project
     async fn new(root: &PathBuf, broadcast: &Arc<Broadcast>) -> Result<Self>
     where
         Self: Sized;
 
+    /// Whether project targets span more than one platform (e.g. iOS and macOS)
+    fn is_multi_platform(&self) -> bool {
+        let mut platforms = self
+            .targets()
+            .values()
+            .map(|info| info.platform)
+            .collect::<Vec<_>>();
+        platforms.sort_unstable();
+        platforms.dedup();
+        platforms.len() > 1
+    }
+
+    /// Path to project README, if any
+    fn readme_path(&self) -> Option<PathBuf> {
+        ["README.md", "README.rst", "README.txt"]
+            .iter()
+            .map(|name| self.root().join(name))
+            .find(|path| path.is_file())
+    }
+
+    /// Open project in Xcode, preferring workspace over xcodeproj
+    async fn open_in_xcode(&self) -> Result<()> {
+        let name = self.name();
+        let path = if self.is_workspace() {
+            self.root().join(format!("{name}.xcworkspace"))
+        } else {
+            self.root().join(format!("{name}.xcodeproj"))
+        };
+
+        open_in_xcode(&path).await
+    }
+
+    /// Remove build artifacts, using `xcodebuild clean` by default
+    async fn clean_build_artifacts(&self, broadcast: &Arc<Broadcast>) -> Result<()> {
+        let name = self.name().to_owned();
+        let cache_build_root = fs::get_build_cache_dir(self.root())?;
+        let task = Task::new(TaskKind::Clean, &name, broadcast.clone());
+        let mut args = vec![
+            "clean".to_string(),
+            "-alltargets".into(),
+            format!("SYMROOT={cache_build_root}"),
+        ];
+
+        if self.is_workspace() {
+            args.extend_from_slice(&["-workspace".into(), format!("{name}.xcworkspace")]);
+        } else {
+            args.extend_from_slice(&["-project".into(), format!("{name}.xcodeproj")]);
+        }
+
+        task.debug(format!("[{name}] {}", args.join(" ")));
+
+        let (_, mut recv) = task.consume(Box::new(XCLogger::new(self.root(), &args)?))?;
+
+        if recv.recv().await.unwrap_or_default() {
+            Ok(())
+        } else {
+            Err(Error::Unexpected(format!(
+                "[{name}] Failed to clean build artifacts"
+            )))
+        }
+    }
+
     #[tracing::instrument(parent = None, name = "Runtime", skip_all, fields(name = self.name()))]
     async fn ensure_setup(
         &mut self,
@@ -176,7 +646,7 @@ pub trait Project:
         use tokio::io::AsyncWriteExt;
 
         let root = self.root();
-        let compile_path = root.join(".compile");
+        let compile_path = self.compile_database_path();
         let is_swift_project = root.join("Package.swift").exists();
 
         /// Server Config
@@ -226,19 +696,20 @@ pub trait Project:
                 self.generate(broadcast).await.map_err(|err| {
                     Error::Setup(self.name().to_string(), format!("Generation failure {err}"))
                 })?;
+                broadcast.update_target_list(self.targets().keys().cloned().collect());
                 self.update_compile_database(broadcast)
                     .await
                     .map_err(|err| {
                         Error::Setup(self.name().to_string(), format!("Compile database: {err}"))
                     })?;
-                broadcast.reload_lsp_server();
+                broadcast.reload_lsp_server(None);
                 return Ok(true);
             }
         }
 
         if !is_swift_project && !compile_path.exists() {
             self.update_compile_database(broadcast).await.unwrap();
-            broadcast.reload_lsp_server();
+            broadcast.reload_lsp_server(None);
             Ok(true)
         } else {
             Ok(false)
@@ -263,16 +734,266 @@ pub async fn project(root: &PathBuf, broadcast: &Arc<Broadcast>) -> Result<Proje
     })
 }
 
-async fn generate_watchignore<P: AsRef<Path>>(root: P) -> Vec<String> {
+/// Sort adjacency list of dependencies (Kahn's algorithm), ties are broken by name
+fn topological_order(graph: &HashMap<String, Vec<String>>) -> Vec<String> {
+    let mut remaining = graph
+        .iter()
+        .map(|(name, dependencies)| {
+            let dependencies = dependencies
+                .iter()
+                .filter(|dependency| graph.contains_key(*dependency) && *dependency != name)
+                .collect::<std::collections::HashSet<_>>();
+            (name, dependencies)
+        })
+        .collect::<HashMap<_, _>>();
+    let mut order = vec![];
+
+    loop {
+        let mut ready = remaining
+            .iter()
+            .filter(|(_, dependencies)| dependencies.is_empty())
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>();
+        if ready.is_empty() {
+            break;
+        }
+        ready.sort_unstable();
+        for name in ready {
+            remaining.remove(name);
+            remaining.values_mut().for_each(|deps| {
+                deps.remove(name);
+            });
+            order.push(name.clone());
+        }
+    }
+
+    let mut cyclic = remaining.into_keys().cloned().collect::<Vec<_>>();
+    cyclic.sort_unstable();
+    order.extend(cyclic);
+    order
+}
+
+/// Update Xcode targets with information only found in their build settings (i.e. `TEST_HOST`)
+/// and in project objects (i.e. target dependencies)
+///
+/// NOTE: xcodeproj doesn't know every platform (i.e. visionOS), so unknown target platforms
+/// are resolved from build settings as well.
+async fn update_xcode_targets_info(xcodeproj: &Path, targets: &mut HashMap<String, TargetInfo>) {
+    let objects = match xcode_project_objects(xcodeproj).await {
+        Ok(objects) => objects,
+        Err(err) => return tracing::warn!("Fail to read project objects: {err}"),
+    };
+    let mut settings = xcode_targets_build_settings(&objects);
+    let mut dependencies = xcode_target_dependencies(&objects);
+    let source_counts = xcode_target_source_counts(&objects);
+
+    for (name, info) in targets.iter_mut() {
+        if let Some(settings) = settings.remove(name) {
+            if info.platform == Platform::Unknown {
+                info.platform = Platform::from_settings_or_default(&settings);
+            }
+            info.test_host = ["TEST_HOST", "BUNDLE_LOADER"]
+                .iter()
+                .flat_map(|key| settings.get(*key))
+                .find(|value| !value.is_empty())
+                .cloned();
+        }
+        info.dependencies = dependencies.remove(name).unwrap_or_default();
+        info.source_count = source_counts.get(name).copied();
+    }
+}
+
+/// Get objects of a given xcodeproj `project.pbxproj`, keyed by object id
+async fn xcode_project_objects(xcodeproj: &Path) -> Result<HashMap<String, serde_json::Value>> {
+    #[derive(serde::Deserialize)]
+    struct PBXProj {
+        objects: HashMap<String, serde_json::Value>,
+    }
+
+    let output = tokio::process::Command::new("/usr/bin/plutil")
+        .args(["-convert", "json", "-o", "-"])
+        .arg(xcodeproj.join("project.pbxproj"))
+        .stdin(std::process::Stdio::null())
+        .kill_on_drop(true)
+        .output();
+    let output = tokio::time::timeout(Duration::from_secs(10), output)
+        .await
+        .map_err(|_| Error::Unexpected("plutil -convert timed out".into()))??;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(Error::Unexpected(stderr));
+    }
+
+    Ok(serde_json::from_slice::<PBXProj>(&output.stdout)?.objects)
+}
+
+/// Map of Xcode target names to the number of files in their sources build phases
+fn xcode_target_source_counts(
+    objects: &HashMap<String, serde_json::Value>,
+) -> HashMap<String, usize> {
+    let ids_of = |object: &serde_json::Value, key: &str| {
+        object
+            .get(key)
+            .and_then(|ids| ids.as_array())
+            .map(|ids| ids.iter().flat_map(|id| id.as_str()).collect::<Vec<_>>())
+            .unwrap_or_default()
+    };
+
+    objects
+        .values()
+        .flat_map(|target| {
+            let name = target.get("name")?.as_str()?;
+            let count = ids_of(target, "buildPhases")
+                .into_iter()
+                .flat_map(|id| objects.get(id))
+                .filter(|phase| {
+                    phase.get("isa").and_then(|isa| isa.as_str()) == Some("PBXSourcesBuildPhase")
+                })
+                .map(|phase| ids_of(phase, "files").len())
+                .sum();
+            Some((name.to_string(), count))
+        })
+        .filter(|(_, count)| *count > 0)
+        .collect()
+}
+
+/// Map of Xcode target names to the names of targets they depend on
+fn xcode_target_dependencies(
+    objects: &HashMap<String, serde_json::Value>,
+) -> HashMap<String, Vec<String>> {
+    let str_of = |object: &serde_json::Value, key: &str| {
+        object.get(key).and_then(|v| v.as_str()).map(String::from)
+    };
+
+    objects
+        .values()
+        .filter(|object| {
+            matches!(
+                object.get("isa").and_then(|isa| isa.as_str()),
+                Some("PBXNativeTarget" | "PBXAggregateTarget" | "PBXLegacyTarget")
+            )
+        })
+        .flat_map(|target| {
+            let dependencies = target
+                .get("dependencies")
+                .and_then(|ids| ids.as_array())
+                .into_iter()
+                .flatten()
+                .flat_map(|id| objects.get(id.as_str()?))
+                .flat_map(|dependency| {
+                    // NOTE: `name` is only set for dependencies on targets of other projects
+                    str_of(dependency, "target")
+                        .and_then(|id| str_of(objects.get(&id)?, "name"))
+                        .or_else(|| str_of(dependency, "name"))
+                })
+                .collect::<Vec<_>>();
+            Some((str_of(target, "name")?, dependencies))
+        })
+        .collect()
+}
+
+/// Map of Xcode target names to their build settings, on top of project build settings
+///
+/// NOTE: settings are read as written in project.pbxproj (i.e. unresolved `$(VARIABLES)`), for
+/// the default configuration only.
+fn xcode_targets_build_settings(
+    objects: &HashMap<String, serde_json::Value>,
+) -> HashMap<String, HashMap<String, String>> {
+    let build_settings = |object: &serde_json::Value| {
+        let list = objects.get(object.get("buildConfigurationList")?.as_str()?)?;
+        let default_name = list
+            .get("defaultConfigurationName")
+            .and_then(|v| v.as_str());
+        let configurations = list
+            .get("buildConfigurations")?
+            .as_array()?
+            .iter()
+            .flat_map(|id| objects.get(id.as_str()?))
+            .collect::<Vec<_>>();
+        let configuration = configurations
+            .iter()
+            .find(|c| c.get("name").and_then(|v| v.as_str()) == default_name)
+            .or_else(|| configurations.first())?;
+        let settings = configuration
+            .get("buildSettings")?
+            .as_object()?
+            .iter()
+            .flat_map(|(key, value)| {
+                let value = match value {
+                    serde_json::Value::String(value) => value.clone(),
+                    serde_json::Value::Array(values) => values
+                        .iter()
+                        .flat_map(|v| v.as_str())
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                    _ => return None,
+                };
+                Some((key.clone(), value))
+            })
+            .collect::<HashMap<_, _>>();
+        Some(settings)
+    };
+    let isa_of = |object: &serde_json::Value| object.get("isa").and_then(|isa| isa.as_str());
+
+    let project_settings = objects
+        .values()
+        .find(|object| isa_of(object) == Some("PBXProject"))
+        .and_then(build_settings)
+        .unwrap_or_default();
+
+    objects
+        .values()
+        .filter(|object| {
+            matches!(
+                isa_of(object),
+                Some("PBXNativeTarget" | "PBXAggregateTarget" | "PBXLegacyTarget")
+            )
+        })
+        .flat_map(|target| {
+            let mut settings = project_settings.clone();
+            settings.extend(build_settings(target).unwrap_or_default());
+            Some((target.get("name")?.as_str()?.to_string(), settings))
+        })
+        .collect()
+}
+
+/// Launch Xcode with a given path
+async fn open_in_xcode(path: &Path) -> Result<()> {
+    if !cfg!(target_os = "macos") {
+        return Err(Error::Unsupported("Opening Xcode".into()));
+    }
+
+    let status = tokio::process::Command::new("open")
+        .args(["-a", "Xcode"])
+        .arg(path)
+        .status()
+        .await?;
+
+    if !status.success() {
+        return Err(Error::Unexpected(format!(
+            "Failed to open {path:?} in Xcode"
+        )));
+    }
+
+    Ok(())
+}
+
+pub(crate) async fn generate_watchignore<P: AsRef<Path>>(root: P) -> Vec<String> {
     let mut default = vec![
         "**/.git/**".into(),
         "**/.*".into(),
         "**/.compile".into(),
+        "**/compile_commands.json".into(),
         "**/build/**".into(),
         "**/.build/**".into(),
         "**/buildServer.json".into(),
         "**/DerivedData/**".into(),
         "**/Derived/**".into(),
+        "**/*.o".into(),
+        "**/*.d".into(),
+        "**/*.bc".into(),
+        "**/*.swp".into(),
     ];
 
     default.extend(
@@ -285,3 +1006,107 @@ async fn generate_watchignore<P: AsRef<Path>>(root: P) -> Vec<String> {
 
     default
 }
+
+#[test]
+fn test_topological_order_dependencies_first() {
+    let graph = HashMap::from([
+        (
+            "App".to_string(),
+            vec!["Feature".to_string(), "Core".to_string()],
+        ),
+        ("Feature".to_string(), vec!["Core".to_string()]),
+        ("Core".to_string(), vec!["External".to_string()]),
+        ("Tools".to_string(), vec![]),
+    ]);
+
+    assert_eq!(
+        topological_order(&graph),
+        ["Core", "Tools", "Feature", "App"]
+    );
+}
+
+#[test]
+fn test_topological_order_cycle_appended() {
+    let graph = HashMap::from([
+        ("App".to_string(), vec!["A".to_string()]),
+        ("A".to_string(), vec!["B".to_string()]),
+        ("B".to_string(), vec!["A".to_string(), "B".to_string()]),
+        ("Core".to_string(), vec![]),
+    ]);
+
+    assert_eq!(topological_order(&graph), ["Core", "A", "App", "B"]);
+}
+
+#[test]
+fn test_xcode_target_dependencies() {
+    let objects = serde_json::json!({
+        "T1": { "isa": "PBXNativeTarget", "name": "App", "dependencies": ["D1", "D2"] },
+        "T2": { "isa": "PBXNativeTarget", "name": "Core", "dependencies": [] },
+        "D1": { "isa": "PBXTargetDependency", "target": "T2" },
+        "D2": { "isa": "PBXTargetDependency", "name": "Remote" },
+        "F1": { "isa": "PBXFileReference", "name": "main.swift" },
+    });
+    let objects = serde_json::from_value(objects).unwrap();
+    let dependencies = xcode_target_dependencies(&objects);
+
+    assert_eq!(dependencies.len(), 2);
+    assert_eq!(dependencies["App"], ["Core", "Remote"]);
+    assert!(dependencies["Core"].is_empty());
+}
+
+#[test]
+fn test_xcode_target_source_counts() {
+    let objects = serde_json::json!({
+        "T1": { "isa": "PBXNativeTarget", "name": "App", "buildPhases": ["P1", "P2"] },
+        "T2": { "isa": "PBXNativeTarget", "name": "Assets", "buildPhases": ["P2"] },
+        "P1": { "isa": "PBXSourcesBuildPhase", "files": ["B1", "B2", "B3"] },
+        "P2": { "isa": "PBXResourcesBuildPhase", "files": ["B4"] },
+    });
+    let objects = serde_json::from_value(objects).unwrap();
+    let source_counts = xcode_target_source_counts(&objects);
+
+    assert_eq!(source_counts, HashMap::from([("App".to_string(), 3)]));
+}
+
+#[test]
+fn test_xcode_targets_build_settings() {
+    let objects = serde_json::json!({
+        "P": { "isa": "PBXProject", "buildConfigurationList": "PL" },
+        "PL": { "isa": "XCConfigurationList", "buildConfigurations": ["PD"] },
+        "PD": {
+            "isa": "XCBuildConfiguration",
+            "name": "Debug",
+            "buildSettings": { "SDKROOT": "iphoneos" }
+        },
+        "T1": { "isa": "PBXNativeTarget", "name": "AppTests", "buildConfigurationList": "TL" },
+        "TL": {
+            "isa": "XCConfigurationList",
+            "buildConfigurations": ["TD", "TR"],
+            "defaultConfigurationName": "Release"
+        },
+        "TD": { "isa": "XCBuildConfiguration", "name": "Debug", "buildSettings": {} },
+        "TR": {
+            "isa": "XCBuildConfiguration",
+            "name": "Release",
+            "buildSettings": {
+                "TEST_HOST": "$(BUILT_PRODUCTS_DIR)/App.app/App",
+                "LD_RUNPATH_SEARCH_PATHS": ["$(inherited)", "@executable_path/Frameworks"]
+            }
+        },
+        "T2": { "isa": "PBXNativeTarget", "name": "App" },
+    });
+    let objects = serde_json::from_value(objects).unwrap();
+    let settings = xcode_targets_build_settings(&objects);
+
+    assert_eq!(settings["AppTests"]["SDKROOT"], "iphoneos");
+    assert_eq!(
+        settings["AppTests"]["TEST_HOST"],
+        "$(BUILT_PRODUCTS_DIR)/App.app/App"
+    );
+    assert_eq!(
+        settings["AppTests"]["LD_RUNPATH_SEARCH_PATHS"],
+        "$(inherited) @executable_path/Frameworks"
+    );
+    assert!(!settings["App"].contains_key("TEST_HOST"));
+    assert_eq!(settings["App"]["SDKROOT"], "iphoneos");
+}
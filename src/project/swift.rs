@@ -2,32 +2,189 @@ use super::*;
 use crate::watcher::Event;
 use crate::{Error, Result};
 use process_stream::Process;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, path::PathBuf};
 use tokio::process::Command;
-use xcodeproj::pbxproj::PBXTargetPlatform;
 
 #[derive(Debug, Serialize, Default)]
 #[serde(default)]
 pub struct SwiftProject {
-    name: String,
     root: PathBuf,
-    targets: HashMap<String, TargetInfo>,
     num_clients: i32,
     watchignore: Vec<String>,
+    adjacent_xcode_project: Option<PathBuf>,
+    #[serde(skip)]
+    manifest: Option<PackageManifest>,
+}
+
+/// Swift package information read from `swift package dump-package`
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(from = "RawPackageManifest")]
+pub struct PackageManifest {
+    /// Package name
+    pub name: String,
+    /// Package targets, excluding test targets
+    pub targets: HashMap<String, TargetInfo>,
+    /// Supported platforms, excluding ones unknown to xbase (e.g. linux)
+    pub platforms: Vec<Platform>,
+    /// Package swift-tools-version
+    pub tools_version: String,
+    /// Identities of declared remote dependencies
+    pub dependencies: Vec<String>,
+    /// Paths of declared local dependencies
+    pub local_dependencies: Vec<PathBuf>,
+    /// Local targets each package target depends on
+    pub target_dependencies: HashMap<String, Vec<String>>,
+}
+
+/// Package information as printed by `dump-package` or `describe --type json`
+#[derive(Deserialize)]
+struct RawPackageManifest {
+    name: String,
+    targets: Vec<RawPackageTarget>,
+    #[serde(default)]
+    platforms: Vec<RawPackagePlatform>,
+    #[serde(default, rename = "toolsVersion", alias = "tools_version")]
+    tools_version: Option<RawToolsVersion>,
+    #[serde(default)]
+    dependencies: Vec<serde_json::Value>,
 }
 
+#[derive(Deserialize)]
+struct RawPackageTarget {
+    name: String,
+    #[serde(default, rename = "type")]
+    kind: String,
+    #[serde(default)]
+    dependencies: Vec<serde_json::Value>,
+    // NOTE: describe output lists dependencies names in `target_dependencies`
+    #[serde(default)]
+    target_dependencies: Vec<String>,
+}
+
+impl RawPackageTarget {
+    /// Names of targets this target depends on, e.g. `{"byName": ["Core", null]}`
+    fn dependency_names(&self) -> Vec<String> {
+        self.dependencies
+            .iter()
+            .flat_map(|dependency| dependency.get("byName").or(dependency.get("target")))
+            .flat_map(|dependency| dependency.get(0)?.as_str())
+            .map(ToString::to_string)
+            .chain(self.target_dependencies.iter().cloned())
+            .collect()
+    }
+}
+
+#[derive(Deserialize)]
+struct RawPackagePlatform {
+    // NOTE: describe output uses `name` instead of `platformName`
+    #[serde(rename = "platformName", alias = "name")]
+    name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawToolsVersion {
+    Versioned {
+        #[serde(rename = "_version")]
+        version: String,
+    },
+    Plain(String),
+}
+
+/// Package information cached in `.build` along with `Package.swift` modification time
+#[derive(Serialize, Deserialize)]
+struct PackageCache {
+    mtime: std::time::SystemTime,
+    manifest: serde_json::Value,
+}
+
+impl From<RawPackageManifest> for PackageManifest {
+    fn from(raw: RawPackageManifest) -> Self {
+        let target_dependencies = raw
+            .targets
+            .iter()
+            .filter(|target| target.kind != "test")
+            .map(|target| (target.name.clone(), target.dependency_names()))
+            .collect();
+
+        let targets = raw
+            .targets
+            .into_iter()
+            .filter(|target| target.kind != "test")
+            .map(|target| {
+                let info = TargetInfo {
+                    platform: Platform::MacOS,
+                    // TODO: get swift configurations
+                    configurations: vec!["Debug".into()],
+                    // NOTE: swift package tests never run inside a host application
+                    test_host: None,
+                    source_count: None,
+                    kind: Some(TargetKind::from_package_type(&target.kind)),
+                    dependencies: target.dependency_names(),
+                };
+                (target.name, info)
+            })
+            .collect();
+
+        let tools_version = match raw.tools_version {
+            Some(RawToolsVersion::Versioned { version }) => version,
+            Some(RawToolsVersion::Plain(version)) => version,
+            None => Default::default(),
+        };
+
+        Self {
+            name: raw.name,
+            targets,
+            platforms: raw
+                .platforms
+                .iter()
+                .map(|p| Platform::from_package_name(&p.name))
+                .filter(|platform| *platform != Platform::Unknown)
+                .collect(),
+            tools_version,
+            dependencies: raw
+                .dependencies
+                .iter()
+                .flat_map(SwiftProject::remote_dependency_identity)
+                .collect(),
+            local_dependencies: raw
+                .dependencies
+                .iter()
+                .flat_map(SwiftProject::local_dependency_path)
+                .collect(),
+            target_dependencies,
+        }
+    }
+}
+
+impl TryFrom<serde_json::Value> for PackageManifest {
+    type Error = Error;
+
+    fn try_from(value: serde_json::Value) -> Result<Self> {
+        serde_json::from_value(value).map_err(|e| Error::DefinitionParsing(e.to_string()))
+    }
+}
+
+#[async_trait::async_trait]
 impl ProjectData for SwiftProject {
     fn root(&self) -> &PathBuf {
         &self.root
     }
 
     fn name(&self) -> &str {
-        &self.name
+        self.manifest
+            .as_ref()
+            .map(|m| m.name.as_str())
+            .unwrap_or_default()
     }
 
     fn targets(&self) -> &HashMap<String, TargetInfo> {
-        &self.targets
+        static EMPTY: Lazy<HashMap<String, TargetInfo>> = Lazy::new(Default::default);
+        self.manifest
+            .as_ref()
+            .map(|m| &m.targets)
+            .unwrap_or(&*EMPTY)
     }
 
     fn clients(&self) -> &i32 {
@@ -41,6 +198,43 @@ impl ProjectData for SwiftProject {
     fn watchignore(&self) -> &Vec<String> {
         &self.watchignore
     }
+
+    fn watchignore_mut(&mut self) -> &mut Vec<String> {
+        &mut self.watchignore
+    }
+
+    fn adjacent_xcode_project(&self) -> Option<&PathBuf> {
+        self.adjacent_xcode_project.as_ref()
+    }
+
+    fn watch_paths(&self) -> Vec<PathBuf> {
+        self.manifest
+            .as_ref()
+            .map(|manifest| manifest.local_dependencies.clone())
+            .unwrap_or_default()
+    }
+
+    fn target_dependency_graph(&self) -> HashMap<String, Vec<String>> {
+        let manifest = match self.manifest.as_ref() {
+            Some(manifest) => manifest,
+            None => return Default::default(),
+        };
+        manifest
+            .targets
+            .keys()
+            .map(|name| {
+                let dependencies = manifest
+                    .target_dependencies
+                    .get(name)
+                    .into_iter()
+                    .flatten()
+                    .filter(|dependency| manifest.targets.contains_key(*dependency))
+                    .cloned()
+                    .collect();
+                (name.clone(), dependencies)
+            })
+            .collect()
+    }
 }
 
 #[async_trait::async_trait]
@@ -52,12 +246,86 @@ impl ProjectBuild for SwiftProject {
         broadcast: &Arc<Broadcast>,
     ) -> Result<(Vec<String>, tokio::sync::mpsc::Receiver<bool>)> {
         let args = vec!["build", "--target", &cfg.target];
-        let mut process = Process::new("/usr/bin/swift");
+        let mut process = Process::new(Self::swift_exec());
 
         process.args(&args);
         process.current_dir(self.root());
+        process.envs(&cfg.env_vars);
         let task = Task::new(TaskKind::Build, cfg.target.as_str(), broadcast.clone());
-        let recv = task.consume(Box::new(process))?;
+        let env_vars = cfg.redacted_env_vars();
+        if !env_vars.is_empty() {
+            task.debug(format!("[{}] {}", cfg.target, env_vars.join(" ")));
+        }
+        let (_, recv) = task.consume(Box::new(process))?;
+        let output_dir = self.root().join(".build");
+        let recv = self.with_post_build_hook(&cfg.target, output_dir, recv, broadcast);
+
+        Ok((vec![], recv))
+    }
+
+    fn build_documentation(
+        &self,
+        target: &str,
+        broadcast: &Arc<Broadcast>,
+    ) -> Result<tokio::sync::mpsc::Receiver<bool>> {
+        let output_dir = self.root().join(".xbase").join("docs");
+        let output = output_dir.display().to_string();
+        // NOTE: swift-docc-plugin refuses to write outside of .build unless explicitly allowed
+        let args = vec![
+            "package",
+            "--allow-writing-to-directory",
+            &output,
+            "generate-documentation",
+            "--target",
+            target,
+            "--output-path",
+            &output,
+        ];
+        let mut process = Process::new(Self::swift_exec());
+
+        process.args(&args);
+        process.current_dir(self.root());
+        let task = Task::new(TaskKind::Build, target, broadcast.clone());
+        task.debug(format!("[{target}] swift {}", args.join(" ")));
+
+        let (_, mut recv) = task.consume(Box::new(process))?;
+        let (tx, rx) = tokio::sync::mpsc::channel(1);
+        let broadcast = broadcast.clone();
+        let target = target.to_string();
+
+        tokio::spawn(async move {
+            let success = recv.recv().await.unwrap_or_default();
+            if success {
+                match Self::documentation_url(&output_dir).await {
+                    Some(url) => broadcast.set_variable("xbase_docs_url", url),
+                    None => broadcast.warn(format!("[{target}] No DocC metadata found")),
+                }
+            }
+            tx.send(success).await.ok();
+        });
+
+        Ok(rx)
+    }
+}
+
+#[async_trait::async_trait]
+impl ProjectTest for SwiftProject {
+    fn test(
+        &self,
+        cfg: &BuildSettings,
+        _device: Option<&Device>,
+        broadcast: &Arc<Broadcast>,
+    ) -> Result<(Vec<String>, tokio::sync::mpsc::Receiver<bool>)> {
+        // NOTE: `swift test` has no `--target`, filter by test target module name instead
+        let args = vec!["test", "--filter", &cfg.target];
+        let mut process = Process::new(Self::swift_exec());
+
+        process.args(&args);
+        process.current_dir(self.root());
+        process.envs(&cfg.env_vars);
+        let task = Task::new(TaskKind::Test, cfg.target.as_str(), broadcast.clone());
+        task.debug(format!("[{}] swift {}", cfg.target, args.join(" ")));
+        let (_, recv) = task.consume(Box::new(process))?;
 
         Ok((vec![], recv))
     }
@@ -75,9 +343,17 @@ impl ProjectRun for SwiftProject {
         Vec<String>,
         tokio::sync::mpsc::Receiver<bool>,
     )> {
+        let kind = self.targets().get(&cfg.target).and_then(|info| info.kind);
+        if let Some(kind) = kind.filter(|kind| !kind.is_executable()) {
+            return Err(Error::Run(format!(
+                "{} is a {kind} target, only executables can be run",
+                cfg.target
+            )));
+        }
+
         let (args, recv) = self.build(cfg, None, broadcast)?;
 
-        let output = std::process::Command::new("/usr/bin/swift")
+        let output = std::process::Command::new(Self::swift_exec())
             .args(["build", "--show-bin-path"])
             .current_dir(self.root())
             .output()?;
@@ -96,7 +372,11 @@ impl ProjectRun for SwiftProject {
 
         tracing::info!("Running {:?} via {bin_path:?}", self.name());
 
-        Ok((Box::new(BinRunner::from_path(&bin_path)), args, recv))
+        let runner = BinRunner::from_path(&bin_path)
+            .with_launch_args(self.get_launch_arguments(cfg))
+            .with_env(self.get_env_vars(cfg));
+
+        Ok((Box::new(runner), args, recv))
     }
 }
 
@@ -106,11 +386,19 @@ impl ProjectCompile for SwiftProject {
         // No Compile database needed for swif projects
         Ok(())
     }
+
+    /// NOTE: swift packages don't generate one, sourcekit-lsp reads Package.swift directly.
+    fn compile_database_path(&self) -> PathBuf {
+        match std::env::var("XBASE_COMPILE_DATABASE") {
+            Ok(path) if !path.is_empty() => self.root().join(path),
+            _ => self.root().join(".build").join("compile_commands.json"),
+        }
+    }
 }
 #[async_trait::async_trait]
 impl ProjectGenerate for SwiftProject {
     fn should_generate(&self, event: &Event) -> bool {
-        let is_config_file = event.file_name() == "Package.swift";
+        let is_config_file = event.affects_config();
         let is_content_update = event.is_content_update_event();
         let is_config_file_update = is_content_update && is_config_file;
 
@@ -122,13 +410,15 @@ impl ProjectGenerate for SwiftProject {
 
     /// Generate xcodeproj
     async fn generate(&mut self, broadcast: &Arc<Broadcast>) -> Result<()> {
-        let mut process: Process = vec!["/usr/bin/swift", "build"].into();
+        let mut process = Process::new(Self::swift_exec());
+        process.args(&["build"]);
         let name = self.root().name().unwrap();
         process.current_dir(self.root());
 
         let task = Task::new(TaskKind::Compile, &name, broadcast.clone());
         let success = task
             .consume(Box::new(process))?
+            .1
             .recv()
             .await
             .unwrap_or_default();
@@ -137,7 +427,8 @@ impl ProjectGenerate for SwiftProject {
             return Err(Error::Generate);
         }
 
-        self.update_project_info().await?;
+        self.update_project_info(false).await?;
+        self.report_unresolved_dependencies(broadcast).await;
 
         tracing::info!("(name: {:?}, targets: {:?})", self.name(), self.targets());
 
@@ -150,10 +441,28 @@ impl Project for SwiftProject {
     async fn new(root: &PathBuf, broadcast: &Arc<Broadcast>) -> Result<Self> {
         let watchignore = generate_watchignore(root).await;
 
+        let adjacent_xcode_project = std::fs::read_dir(root)?
+            .flatten()
+            .map(|entry| entry.path())
+            .find(|path| {
+                matches!(
+                    path.extension().and_then(|ext| ext.to_str()),
+                    Some("xcodeproj" | "xcworkspace")
+                )
+            });
+
+        if let Some(ref path) = adjacent_xcode_project {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            broadcast.warn(format!(
+                "Found {name}; consider using XcodeProject for richer features"
+            ));
+        }
+
         let mut project = Self {
             root: root.clone(),
             watchignore,
             num_clients: 1,
+            adjacent_xcode_project,
             ..Self::default()
         };
 
@@ -162,7 +471,8 @@ impl Project for SwiftProject {
             project.generate(broadcast).await?;
             return Ok(project);
         } else {
-            project.update_project_info().await?;
+            project.update_project_info(false).await?;
+            project.report_unresolved_dependencies(broadcast).await;
             tracing::info!(
                 "(name: {:?}, targets: {:?})",
                 project.name(),
@@ -172,71 +482,294 @@ impl Project for SwiftProject {
 
         Ok(project)
     }
+
+    async fn open_in_xcode(&self) -> Result<()> {
+        super::open_in_xcode(self.root()).await
+    }
+
+    async fn clean_build_artifacts(&self, broadcast: &Arc<Broadcast>) -> Result<()> {
+        let build_root = self.root().join(".build");
+        let task = Task::new(TaskKind::Clean, self.name(), broadcast.clone());
+
+        if build_root.exists() {
+            task.info(format!("Removing {}", build_root.display()));
+            if let Err(err) = tokio::fs::remove_dir_all(&build_root).await {
+                task.error(err.to_string());
+                task.finish(false);
+                return Err(err.into());
+            }
+        }
+
+        task.finish(true);
+        Ok(())
+    }
 }
 
 impl SwiftProject {
+    /// Get environment variables to set before launching a target executable.
+    ///
+    /// User defined variables in BuildSettings take precedence over runtime defaults.
+    pub fn get_env_vars(&self, cfg: &BuildSettings) -> HashMap<String, String> {
+        let mut env = HashMap::from([("SWIFT_BACKTRACE".to_string(), "enable=yes".to_string())]);
+        env.extend(cfg.env.clone());
+        env
+    }
+
+    /// Get [`Error::ToolchainTooOld`] if stderr reports an unsupported swift-tools-version.
+    ///
+    /// Versions are read in order of appearance, required version first.
+    fn toolchain_too_old_error(stderr: &str) -> Option<Error> {
+        if !stderr.contains("error: package requires minimum Swift version") {
+            return None;
+        }
+        let mut versions = stderr
+            .split(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .map(|s| s.trim_matches('.'))
+            .filter(|s| s.contains('.') && s.starts_with(|c: char| c.is_ascii_digit()))
+            .map(ToString::to_string);
+
+        Some(Error::ToolchainTooOld {
+            required: versions.next().unwrap_or_else(|| "unknown".into()),
+            installed: versions.next().unwrap_or_else(|| "unknown".into()),
+        })
+    }
+
     /// Read Package.swift and update internal state
-    async fn update_project_info(&mut self) -> Result<()> {
-        use anyhow::anyhow;
-        use serde_json::{Map, Value};
+    ///
+    /// Package information is read from cache unless `force` is set or `Package.swift` got
+    /// modified since it was cached.
+    async fn update_project_info(&mut self, force: bool) -> Result<()> {
+        let mtime = tokio::fs::metadata(self.root.join("Package.swift"))
+            .await
+            .and_then(|metadata| metadata.modified())
+            .ok();
+
+        let cached = match force {
+            true => None,
+            false => self.read_package_cache(mtime).await,
+        };
+
+        let value = match cached {
+            Some(value) => {
+                tracing::info!("Read package information from cache");
+                value
+            }
+            None => {
+                let value = self.read_package_info().await?;
+                self.write_package_cache(mtime, &value).await;
+                value
+            }
+        };
+
+        let mut manifest = PackageManifest::try_from(value)?;
+        let root = self.root.clone();
+        let targets = std::mem::take(&mut manifest.targets);
+
+        // NOTE: counting sources walks the filesystem synchronously
+        manifest.targets = tokio::task::spawn_blocking(move || {
+            targets
+                .into_iter()
+                .map(|(name, info)| {
+                    let info = info.with_source_count(&root, &name);
+                    (name, info)
+                })
+                .collect::<HashMap<_, _>>()
+        })
+        .await
+        .map_err(|e| Error::JoinError(e.to_string()))?;
+
+        self.manifest = Some(manifest);
+
+        Ok(())
+    }
+
+    /// Path to cached package information
+    fn package_cache_path(&self) -> PathBuf {
+        self.root.join(".build").join("xbase-package-cache.json")
+    }
+
+    /// Read cached package information if it was cached for the given `Package.swift` mtime
+    async fn read_package_cache(
+        &self,
+        mtime: Option<std::time::SystemTime>,
+    ) -> Option<serde_json::Value> {
+        let content = tokio::fs::read(self.package_cache_path()).await.ok()?;
+        let cache = serde_json::from_slice::<PackageCache>(&content).ok()?;
+        (Some(cache.mtime) == mtime).then(|| cache.manifest)
+    }
+
+    /// Cache package information for the given `Package.swift` mtime
+    async fn write_package_cache(
+        &self,
+        mtime: Option<std::time::SystemTime>,
+        manifest: &serde_json::Value,
+    ) {
+        let mtime = match mtime {
+            Some(mtime) => mtime,
+            None => return,
+        };
+        let cache = PackageCache {
+            mtime,
+            manifest: manifest.clone(),
+        };
+        let write = async {
+            let content = serde_json::to_vec(&cache)?;
+            tokio::fs::write(self.package_cache_path(), content).await?;
+            Ok::<_, Error>(())
+        };
+        if let Err(err) = write.await {
+            tracing::warn!("Failed to cache package information: {err}");
+        }
+    }
+
+    /// Read package information via `dump-package`, falling back to `describe`
+    async fn read_package_info(&self) -> Result<serde_json::Value> {
+        let output = self.swift_package(&["dump-package"]).await?;
+
+        let value = if output.status.success() {
+            tracing::info!("Read package information via `dump-package`");
+            serde_json::from_slice::<serde_json::Value>(&output.stdout)
+                .map_err(|e| Error::DefinitionParsing(e.to_string()))?
+        } else {
+            let error = String::from_utf8_lossy(&output.stderr).replace("\n", " ");
+            if let Some(err) = Self::toolchain_too_old_error(&error) {
+                tracing::error!("{err}");
+                return Err(err);
+            }
+            tracing::warn!("`dump-package` failed, falling back to `describe`: {error}");
+
+            let output = self.swift_package(&["describe", "--type", "json"]).await?;
+            if output.status.success() {
+                tracing::info!("Read package information via `describe`");
+                serde_json::from_slice::<serde_json::Value>(&output.stdout)
+                    .map_err(|e| Error::DefinitionParsing(e.to_string()))?
+            } else {
+                let error = String::from_utf8(output.stderr)
+                    .unwrap_or_default()
+                    .split("\n")
+                    .collect();
+                tracing::error!("Fail to read swift package information {error}");
+                return Err(Error::DefinitionParsing(error));
+            }
+        };
+
+        Ok(value)
+    }
 
-        let output = Command::new("/usr/bin/swift")
-            .args(["package", "dump-package"])
+    /// Get identity of a remote dependency declared in `dump-package` output
+    fn remote_dependency_identity(dependency: &serde_json::Value) -> Option<String> {
+        // NOTE: swift >= 5.6 nest dependencies under `sourceControl`
+        if let Some(source) = dependency.get("sourceControl").and_then(|v| v.get(0)) {
+            source.get("location")?.get("remote")?;
+            return Some(source.get("identity")?.as_str()?.to_lowercase());
+        }
+
+        let url = dependency.get("url")?.as_str()?;
+        if url.starts_with('/') || url.starts_with("file://") {
+            return None;
+        }
+
+        let name = url.trim_end_matches('/').rsplit('/').next()?;
+        Some(name.trim_end_matches(".git").to_lowercase())
+    }
+
+    /// Get path of a local dependency declared in `dump-package` output
+    fn local_dependency_path(dependency: &serde_json::Value) -> Option<PathBuf> {
+        // NOTE: swift >= 5.6 nest dependencies under `fileSystem`
+        if let Some(source) = dependency.get("fileSystem").and_then(|v| v.get(0)) {
+            return Some(source.get("path")?.as_str()?.into());
+        }
+
+        let url = dependency.get("url")?.as_str()?;
+        let path = url.strip_prefix("file://").unwrap_or(url);
+        path.starts_with('/').then(|| path.into())
+    }
+
+    /// Broadcast a diagnostic for each declared remote dependency that couldn't be resolved
+    async fn report_unresolved_dependencies(&self, broadcast: &Arc<Broadcast>) {
+        use serde_json::Value;
+
+        fn collect_identities(value: &Value, identities: &mut Vec<String>) {
+            if let Some(identity) = value.get("identity").and_then(|v| v.as_str()) {
+                identities.push(identity.to_lowercase());
+            }
+            if let Some(dependencies) = value.get("dependencies").and_then(|v| v.as_array()) {
+                dependencies
+                    .iter()
+                    .for_each(|v| collect_identities(v, identities));
+            }
+        }
+
+        let declared = match self.manifest() {
+            Some(manifest) if !manifest.dependencies.is_empty() => &manifest.dependencies,
+            _ => return,
+        };
+
+        let output = match self
+            .swift_package(&["show-dependencies", "--format", "json"])
+            .await
+        {
+            Ok(output) => output,
+            Err(err) => return tracing::error!("Fail to run show-dependencies: {err}"),
+        };
+
+        let mut resolved = vec![];
+        if output.status.success() {
+            match serde_json::from_slice::<Value>(&output.stdout) {
+                Ok(value) => collect_identities(&value, &mut resolved),
+                Err(err) => tracing::error!("Fail to parse show-dependencies output: {err}"),
+            }
+        }
+
+        let manifest_path = self.root().join("Package.swift");
+        for identity in declared.iter().filter(|d| !resolved.contains(d)) {
+            broadcast.diagnostic(
+                &manifest_path,
+                1,
+                format!("Unable to resolve package dependency `{identity}`"),
+                ContentLevel::Error,
+            );
+        }
+    }
+
+    /// Get documentation root url from DocC archive `metadata.json`
+    async fn documentation_url(output_dir: &Path) -> Option<String> {
+        let content = tokio::fs::read(output_dir.join("metadata.json"))
+            .await
+            .ok()?;
+        let metadata = serde_json::from_slice::<serde_json::Value>(&content).ok()?;
+        let name = metadata.get("bundleDisplayName")?.as_str()?.to_lowercase();
+        let root = output_dir.join("documentation").join(name);
+        Some(format!("file://{}", root.display()))
+    }
+
+    /// Path to swift executable, overridable with `SWIFT_EXEC` env variable
+    fn swift_exec() -> String {
+        let swift = std::env::var("SWIFT_EXEC")
+            .ok()
+            .filter(|path| !path.is_empty())
+            .unwrap_or_else(|| "/usr/bin/swift".into());
+        tracing::debug!("Using swift executable: {swift}");
+        swift
+    }
+
+    /// Run `swift package` with given arguments in project root
+    async fn swift_package(&self, args: &[&str]) -> Result<std::process::Output> {
+        Ok(Command::new(Self::swift_exec())
+            .arg("package")
+            .args(args)
             .current_dir(self.root())
             .stdin(std::process::Stdio::null())
             .stdout(std::process::Stdio::piped())
             .stderr(std::process::Stdio::piped())
             .spawn()?
             .wait_with_output()
-            .await?;
-
-        let map = if output.status.success() {
-            serde_json::from_slice::<Map<String, Value>>(&output.stdout)
-                .map_err(|e| Error::DefinitionParsing(e.to_string()))?
-        } else {
-            let error = String::from_utf8(output.stderr)
-                .unwrap_or_default()
-                .split("\n")
-                .collect();
-            tracing::error!("Fail to read swift package information {error}");
-            return Err(Error::DefinitionParsing(error));
-        };
-
-        // TODO(swift-package): only provide run service for executables
-        self.name = map
-            .get("name")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string())
-            .ok_or_else(|| anyhow!("expected package name field is missing!"))?;
-
-        self.targets = map
-            .get("targets")
-            .and_then(|v| v.as_array())
-            .ok_or_else(|| anyhow!("expected package target field is missing!"))?
-            .into_iter()
-            .flat_map(|v| v.as_object())
-            .flat_map(|target_info| {
-                let name = target_info.get("name")?.as_str()?.to_string();
-                if !target_info
-                    .get("type")
-                    .and_then(|s| s.as_str())
-                    .map(|s| s == "test")
-                    .unwrap_or_default()
-                {
-                    Some((
-                        name,
-                        TargetInfo {
-                            platform: PBXTargetPlatform::MacOS.to_string(),
-                            // TODO: get swift configurations
-                            configurations: vec!["Debug".into()],
-                        },
-                    ))
-                } else {
-                    None
-                }
-            })
-            .collect();
+            .await?)
+    }
 
-        Ok(())
+    /// Get a reference to the cached package manifest.
+    #[must_use]
+    pub fn manifest(&self) -> Option<&PackageManifest> {
+        self.manifest.as_ref()
     }
 }
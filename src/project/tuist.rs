@@ -29,6 +29,7 @@ pub struct TuistProject {
     manifest_files: Vec<String>,
 }
 
+#[async_trait::async_trait]
 impl ProjectData for TuistProject {
     fn root(&self) -> &PathBuf {
         &self.root
@@ -53,6 +54,10 @@ impl ProjectData for TuistProject {
     fn watchignore(&self) -> &Vec<String> {
         &self.watchignore
     }
+
+    fn watchignore_mut(&mut self) -> &mut Vec<String> {
+        &mut self.watchignore
+    }
 }
 #[async_trait::async_trait]
 impl ProjectCompile for TuistProject {
@@ -81,7 +86,7 @@ impl ProjectCompile for TuistProject {
 
             let xclogger = XCLogger::new(&root, &args)?;
             xccommands.push(xclogger.compile_commands.clone());
-            tasks_recvs.push(task.consume(Box::new(xclogger))?);
+            tasks_recvs.push(task.consume(Box::new(xclogger))?.1);
 
             let argsstr = args.join(" ");
             tracing::info!("Building Manifest ...");
@@ -102,7 +107,7 @@ impl ProjectCompile for TuistProject {
 
             let xclogger = XCLogger::new(&root, &args)?;
             xccommands.push(xclogger.compile_commands.clone());
-            tasks_recvs.push(task.consume(Box::new(xclogger))?);
+            tasks_recvs.push(task.consume(Box::new(xclogger))?.1);
             let argsstr = args.join(" ");
             tracing::info!("Building {} ...", scheme.name);
             tracing::trace!("\n\n xcodebuild {argsstr}\n\n");
@@ -131,7 +136,7 @@ impl ProjectCompile for TuistProject {
         xccommands.dedup();
 
         let json = serde_json::to_vec_pretty(&xccommands)?;
-        tokio::fs::write(root.join(".compile"), &json).await?;
+        tokio::fs::write(self.compile_database_path(), &json).await?;
 
         Ok(())
     }
@@ -173,6 +178,7 @@ impl ProjectGenerate for TuistProject {
                 self.targets.insert(key, info.into());
             }
         }
+        update_xcode_targets_info(&self.xcodeproj_path, &mut self.targets).await;
 
         Ok(())
     }
@@ -288,6 +294,7 @@ impl Project for TuistProject {
             .into_iter()
             .map(|(k, info)| (k, info.into()))
             .collect();
+        update_xcode_targets_info(&project.xcodeproj_path, &mut project.targets).await;
 
         tracing::info!("[{}] targets: {:?}", project.name(), project.targets());
 
@@ -298,5 +305,8 @@ impl Project for TuistProject {
 #[async_trait::async_trait]
 impl ProjectBuild for TuistProject {}
 
+#[async_trait::async_trait]
+impl ProjectTest for TuistProject {}
+
 #[async_trait::async_trait]
 impl ProjectRun for TuistProject {}
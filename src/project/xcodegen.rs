@@ -19,6 +19,7 @@ pub struct XCodeGenProject {
     xcodeproj: xcodeproj::XCodeProject,
 }
 
+#[async_trait::async_trait]
 impl ProjectData for XCodeGenProject {
     fn root(&self) -> &PathBuf {
         &self.root
@@ -43,6 +44,10 @@ impl ProjectData for XCodeGenProject {
     fn watchignore(&self) -> &Vec<String> {
         &self.watchignore
     }
+
+    fn watchignore_mut(&mut self) -> &mut Vec<String> {
+        &mut self.watchignore
+    }
 }
 
 #[async_trait::async_trait]
@@ -64,14 +69,15 @@ impl ProjectCompile for XCodeGenProject {
 
         let success = task
             .consume(Box::new(xclogger))?
+            .1
             .recv()
             .await
             .unwrap_or_default();
         if success {
             let compile_db = CC::new(compile_commands.lock().await.to_vec());
             let json = serde_json::to_vec_pretty(&compile_db)?;
-            tokio::fs::write(root.join(".compile"), &json).await?;
-            broadcast.reload_lsp_server();
+            tokio::fs::write(self.compile_database_path(), &json).await?;
+            broadcast.reload_lsp_server(None);
             Ok(())
         } else {
             Err(Error::Compile)
@@ -82,7 +88,7 @@ impl ProjectCompile for XCodeGenProject {
 #[async_trait::async_trait]
 impl ProjectGenerate for XCodeGenProject {
     fn should_generate(&self, event: &Event) -> bool {
-        let is_config_file = event.file_name() == "project.yml";
+        let is_config_file = event.affects_config();
         let is_content_update = event.is_content_update_event();
         let is_config_file_update = is_content_update && is_config_file;
 
@@ -131,6 +137,7 @@ impl ProjectGenerate for XCodeGenProject {
                 self.targets.insert(key, info.into());
             }
         }
+        update_xcode_targets_info(&xcodeproj_paths[0], &mut self.targets).await;
 
         Ok(())
     }
@@ -172,6 +179,7 @@ impl Project for XCodeGenProject {
                 .into_iter()
                 .map(|(k, info)| (k, info.into()))
                 .collect();
+            update_xcode_targets_info(xcpath, &mut project.targets).await;
             tracing::debug!("Targets: {:?} ", project.targets);
         } else {
             tracing::info!("Generating xcodeproj ...");
@@ -191,5 +199,8 @@ impl Project for XCodeGenProject {
 #[async_trait::async_trait]
 impl ProjectBuild for XCodeGenProject {}
 
+#[async_trait::async_trait]
+impl ProjectTest for XCodeGenProject {}
+
 #[async_trait::async_trait]
 impl ProjectRun for XCodeGenProject {}
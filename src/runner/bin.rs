@@ -1,21 +1,44 @@
 use crate::{Error, Result, Runner, Task};
 use process_stream::Process;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use xclog::XCBuildSettings;
 
 pub struct BinRunner {
     path: PathBuf,
+    launch_args: Vec<String>,
+    env: HashMap<String, String>,
 }
 
 impl BinRunner {
     pub fn from_build_info(info: &XCBuildSettings) -> Self {
         let path = info.path_to_output_binary().unwrap_or_default();
-        Self { path }
+        Self {
+            path,
+            launch_args: vec![],
+            env: Default::default(),
+        }
     }
 
     pub fn from_path<P: AsRef<Path>>(path: P) -> Self {
         let path = path.as_ref().into();
-        Self { path }
+        Self {
+            path,
+            launch_args: vec![],
+            env: Default::default(),
+        }
+    }
+
+    /// Set arguments to pass to the binary
+    pub fn with_launch_args(mut self, launch_args: Vec<String>) -> Self {
+        self.launch_args = launch_args;
+        self
+    }
+
+    /// Set environment variables to apply before spawning the binary
+    pub fn with_env(mut self, env: HashMap<String, String>) -> Self {
+        self.env = env;
+        self
     }
 }
 
@@ -26,6 +49,10 @@ impl Runner for BinRunner {
             return Err(Error::Run(format!("{:?} doesn't exist!", self.path)));
         }
 
-        Ok(Process::new(&self.path))
+        let mut process = Process::new(&self.path);
+        process.args(&self.launch_args);
+        process.envs(&self.env);
+
+        Ok(process)
     }
 }
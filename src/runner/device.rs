@@ -1,16 +1,18 @@
 use derive_deref_rs::Deref;
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 use std::{collections::HashMap, hash::Hash};
 use tap::Pipe;
 use typescript_type_def::TypeDef;
-use xcodeproj::pbxproj::PBXTargetPlatform;
 
-use crate::DeviceLookup;
+use crate::{DeviceLookup, Platform};
 
-#[derive(Clone, Debug, Serialize, derive_deref_rs::Deref)]
+#[derive(Clone, Debug, Serialize, Deserialize, derive_deref_rs::Deref)]
+#[serde(rename_all = "camelCase")]
 pub struct Device {
-    pub platform: PBXTargetPlatform,
+    pub platform: Platform,
     #[serde(flatten)]
     #[deref]
     inner: simctl::Device,
@@ -42,21 +44,35 @@ impl Hash for Device {
 impl From<simctl::Device> for Device {
     fn from(inner: simctl::Device) -> Self {
         let ref id = inner.runtime_identifier;
-        let platform = PBXTargetPlatform::from_identifer(id);
+        let platform = Platform::from_identifer(id).unwrap_or_else(|err| {
+            tracing::error!("{err}");
+            Platform::Unknown
+        });
         Self { inner, platform }
     }
 }
 
 impl Device {
+    /// Deserialize device from json sent through RPC
+    pub fn from_json(s: &str) -> crate::Result<Self> {
+        Ok(serde_json::from_str(s)?)
+    }
+
+    /// Serialize device to json for RPC transport
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
     /// Get special build arguments to run on current device.
     // -sdk driverkit -sdk iphoneos -sdk macosx -sdk appletvos -sdk watchos
     pub fn special_build_args(&self) -> Vec<String> {
         match self.platform {
-            PBXTargetPlatform::IOS => vec!["-sdk".into(), "iphonesimulator".into()],
-            PBXTargetPlatform::WatchOS => vec!["-sdk".into(), "watchsimulator".into()],
-            PBXTargetPlatform::TvOS => vec!["-sdk".into(), "appletvsimulator".into()],
-            PBXTargetPlatform::MacOS => vec!["-sdk".into(), "macosx".into()],
-            PBXTargetPlatform::Unknown => vec![],
+            Platform::IOS => vec!["-sdk".into(), "iphonesimulator".into()],
+            Platform::WatchOS => vec!["-sdk".into(), "watchsimulator".into()],
+            Platform::TvOS => vec!["-sdk".into(), "appletvsimulator".into()],
+            Platform::MacOS => vec!["-sdk".into(), "macosx".into()],
+            Platform::VisionOS => vec!["-sdk".into(), "xrsimulator".into()],
+            Platform::Unknown => vec![],
         }
     }
 }
@@ -64,16 +80,17 @@ impl Device {
 static DEVICES: Lazy<Devices> = Lazy::new(Default::default);
 
 /// Represntaiton of Project runners index by Platfrom
-#[derive(Debug, Serialize, Deserialize, TypeDef)]
+#[derive(Debug, Clone, Serialize, Deserialize, TypeDef)]
 pub struct Runners(HashMap<String, Vec<DeviceLookup>>);
 
 impl Default for Runners {
     fn default() -> Self {
         let devices = &*DEVICES;
         vec![
-            PBXTargetPlatform::IOS,
-            PBXTargetPlatform::WatchOS,
-            PBXTargetPlatform::TvOS,
+            Platform::IOS,
+            Platform::WatchOS,
+            Platform::TvOS,
+            Platform::VisionOS,
         ]
         .into_iter()
         .map(|p| {
@@ -112,4 +129,57 @@ impl Devices {
     pub fn from_lookup(lookup: Option<DeviceLookup>) -> Option<Device> {
         lookup.and_then(|d| DEVICES.get(&d.id)).cloned()
     }
+
+    /// Query available devices via simctl, cached for 30 seconds
+    pub fn list() -> crate::Result<Vec<Device>> {
+        static CACHE: Lazy<Mutex<Option<(Instant, Vec<Device>)>>> = Lazy::new(Default::default);
+
+        let mut cache = match CACHE.lock() {
+            Ok(cache) => cache,
+            Err(err) => err.into_inner(),
+        };
+
+        if let Some((queried_at, devices)) = cache.as_ref() {
+            if queried_at.elapsed() < Duration::from_secs(30) {
+                return Ok(devices.clone());
+            }
+        }
+
+        let devices = simctl::Simctl::new()
+            .list()?
+            .devices()
+            .to_vec()
+            .into_iter()
+            .filter(|d| d.is_available)
+            .map(Device::from)
+            .collect::<Vec<_>>();
+
+        *cache = Some((Instant::now(), devices.clone()));
+
+        Ok(devices)
+    }
+}
+
+#[test]
+fn test_device_json_roundtrip() {
+    let device = Device::from_json(
+        r#"{
+            "platform": "iOS",
+            "udid": "B3DA9F7B-6A44-4B3A-8A3B-0C6B8B0C2D11",
+            "name": "iPhone 13",
+            "isAvailable": true,
+            "state": "Shutdown",
+            "deviceTypeIdentifier": "com.apple.CoreSimulator.SimDeviceType.iPhone-13",
+            "dataPath": "/tmp/data",
+            "logPath": "/tmp/log",
+            "runtime_identifier": "com.apple.CoreSimulator.SimRuntime.iOS-15-5"
+        }"#,
+    )
+    .unwrap();
+
+    let roundtrip = Device::from_json(&device.to_json()).unwrap();
+
+    assert_eq!(roundtrip, device);
+    assert_eq!(roundtrip.platform, Platform::IOS);
+    assert_eq!(roundtrip.name, "iPhone 13");
 }
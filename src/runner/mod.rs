@@ -7,6 +7,7 @@ use async_trait::async_trait;
 use process_stream::{Process, ProcessExt, StreamExt};
 use std::sync::Arc;
 use std::sync::Weak;
+use tokio::sync::mpsc::Receiver;
 use tokio::task::JoinHandle;
 
 pub use {bin::*, device::*, simulator::*};
@@ -50,7 +51,7 @@ impl Watchable for RunService {
         project: &mut ProjectImpl,
         _event: &Event,
         broadcast: &Arc<Broadcast>,
-    ) -> Result<()> {
+    ) -> Result<Option<Receiver<bool>>> {
         let Self { settings, .. } = self;
 
         let mut handler = self.handler.clone().lock_owned().await;
@@ -75,7 +76,7 @@ impl Watchable for RunService {
 
         *handler = Some(RunHandler::new(target, runner, broadcast)?);
 
-        Ok(())
+        Ok(None)
     }
 
     /// A function that controls whether a a Watchable should restart
@@ -89,11 +90,29 @@ impl Watchable for RunService {
     }
 
     /// Drop watchable for watching a given file system
-    async fn discard(&self) {
+    async fn discard(&self) -> Result<()> {
         self.handler.clone().lock_owned().await.take().map(|v| {
             v.process().abort();
             v.inner().abort();
         });
+        Ok(())
+    }
+
+    fn metadata(&self) -> serde_json::Value {
+        // NOTE: handler is locked while (re)starting, in which case running is unknown
+        let running = self
+            .handler
+            .try_lock()
+            .ok()
+            .map(|handler| handler.as_ref().map_or(false, |h| !h.inner().is_finished()));
+
+        serde_json::json!({
+            "kind": "run",
+            "root": self.root,
+            "settings": self.settings,
+            "device": self.device.as_ref().map(|d| d.name.clone()),
+            "running": running,
+        })
     }
 }
 
@@ -128,11 +147,11 @@ impl RunHandler {
                 match output {
                     Output(msg) => {
                         if !msg.contains("ignoring singular matrix") {
-                            broadcast.log_info(msg);
+                            broadcast.log_process_item(Output(msg));
                         }
                     }
                     Error(msg) => {
-                        broadcast.log_error(msg);
+                        broadcast.log_process_item(Error(msg));
                     }
                     // TODO: this should be skipped when user re-run the app
                     Exit(code) => {
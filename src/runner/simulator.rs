@@ -11,6 +11,7 @@ pub struct SimulatorRunner {
     pub device: Device,
     pub app_id: String,
     pub output_dir: PathBuf,
+    pub launch_args: Vec<String>,
 }
 
 #[async_trait::async_trait]
@@ -29,9 +30,16 @@ impl SimulatorRunner {
             device,
             app_id: info.product_bundle_identifier.clone(),
             output_dir: info.metal_library_output_dir.clone(),
+            launch_args: vec![],
         }
     }
 
+    /// Set arguments to pass to the launched application
+    pub fn with_launch_args(mut self, launch_args: Vec<String>) -> Self {
+        self.launch_args = launch_args;
+        self
+    }
+
     pub async fn boot<'a>(&self, task: &Task) -> Result<()> {
         match pid::get_pid_by_name("Simulator") {
             Err(Error::Lookup(_, _)) => {
@@ -82,6 +90,7 @@ impl SimulatorRunner {
         ];
 
         process.args(args);
+        process.args(&self.launch_args);
 
         task.info(self.connected_msg());
 
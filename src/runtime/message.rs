@@ -1,5 +1,5 @@
 use crate::server::{BuildRequest, RunRequest};
-use crate::{Event, PathExt};
+use crate::{Event, Message, PathExt, UnixSocketPath};
 use std::{collections::HashSet, path::PathBuf};
 use tokio::sync::mpsc;
 
@@ -16,6 +16,12 @@ pub enum PRMessage {
     Run(RunRequest),
     /// Process Build Request
     Build(BuildRequest),
+    /// Forward a message to project clients
+    Broadcast(Message),
+    /// Open project in Xcode
+    OpenXcode,
+    /// Reveal project README in clients
+    RevealReadme,
 }
 
 #[derive(Debug)]
@@ -23,7 +29,7 @@ pub struct PRMessageSender {
     /// Project Root
     root: PathBuf,
     /// Message Broadcaster address
-    broadcaster_adderss: PathBuf,
+    broadcaster_adderss: UnixSocketPath,
     /// PRMessage sender
     sender: mpsc::UnboundedSender<PRMessage>,
     /// Connect Cilents
@@ -33,7 +39,7 @@ pub struct PRMessageSender {
 impl PRMessageSender {
     pub fn new(
         root: &PathBuf,
-        baddress: &PathBuf,
+        baddress: &UnixSocketPath,
         sender: &mpsc::UnboundedSender<PRMessage>,
     ) -> Self {
         Self {
@@ -76,7 +82,7 @@ impl PRMessageSender {
         self.sender.is_closed()
     }
 
-    pub fn broadcaster_adderss(&self) -> &PathBuf {
+    pub fn broadcaster_adderss(&self) -> &UnixSocketPath {
         &self.broadcaster_adderss
     }
 
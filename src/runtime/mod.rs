@@ -22,6 +22,8 @@ pub struct ProjectRuntime {
     watcher_subscribers: WatchSubscribers,
     /// Build/Run requests subscribed to changes
     watcher_state: WatcherState,
+    /// Filesystem watcher event loop, aborted when runtime is dropped
+    watcher_handle: Option<WatcherHandle>,
     /// Connect clients id
     clients: u32,
     /// Notifer to notify listeners that this runtime is no longer active
@@ -43,6 +45,7 @@ impl ProjectRuntime {
             clients: Default::default(),
             abort: Default::default(),
             watcher_state: WatcherState::new(),
+            watcher_handle: None,
             watcher_subscribers,
             broadcaster,
             project,
@@ -63,28 +66,21 @@ impl ProjectRuntime {
             self.broadcaster.error(format!("[{}]  {err}", self.name));
         };
 
-        tokio::spawn(
-            Watcher::new(
-                &self.name,
-                &self.watcher_state,
-                &self.sender,
-                &self.abort,
-                self.project.root(),
-                self.project.watchignore(),
-            )
-            .start(),
-        );
-        self.on_connect(id);
+        if let Err(err) = self.spawn_watcher() {
+            self.broadcaster.error(format!("[{}] {err}", self.name));
+        };
+        self.on_connect(id).await;
 
         info!("[Initialized] -------------------------");
         while let Some(message) = self.receiver.recv().await {
             match message {
-                PRMessage::Connect(id) => self.on_connect(id),
+                PRMessage::Connect(id) => self.on_connect(id).await,
                 PRMessage::Disconnect(id) => {
                     info!("Disconnected [{id}]");
                     self.clients -= 1;
                     self.broadcaster.send(Some(id), Message::Disconnect);
                     if self.clients.eq(&0) {
+                        info!("Watch stats: {:?}", self.watcher_subscribers.stats());
                         self.broadcaster.abort();
                         self.abort.notify_waiters();
                         tokio::spawn(async move { runtimes().await.remove(self.project.root()) });
@@ -94,25 +90,102 @@ impl ProjectRuntime {
                 PRMessage::FSEvent(event) => self.on_fs_event(event).await,
                 PRMessage::Run(req) => self.on_run(req).await,
                 PRMessage::Build(req) => self.on_build(req).await,
+                PRMessage::Broadcast(message) => self.broadcaster.send(None, message),
+                PRMessage::RevealReadme => match self.project.readme_path() {
+                    Some(path) => self.broadcaster.reveal_file(path),
+                    None => self
+                        .broadcaster
+                        .warn(format!("[{}] No README found", self.name)),
+                },
+                PRMessage::OpenXcode => {
+                    if let Err(err) = self.project.open_in_xcode().await {
+                        self.broadcaster.error(format!("[{}] {err}", self.name));
+                    }
+                }
             }
         }
         info!("[Dropped]");
     }
 
-    fn on_connect(&mut self, id: u32) {
+    /// Create and spawn filesystem watcher, replacing current one if any
+    fn spawn_watcher(&mut self) -> Result<()> {
+        let watcher = Watcher::new(
+            &self.name,
+            &self.watcher_state,
+            &self.sender,
+            &self.abort,
+            self.project.root(),
+            self.project.watchignore(),
+            *WATCH_DEBOUNCE,
+        )?;
+        self.watcher_handle = Some(watcher.spawn());
+        self.watch_additional_paths();
+        Ok(())
+    }
+
+    /// Watch project directories outside of its root, see [`ProjectData::watch_paths`]
+    fn watch_additional_paths(&self) {
+        let handle = match self.watcher_handle {
+            Some(ref handle) => handle,
+            None => return,
+        };
+        for path in self.project.watch_paths() {
+            if let Err(err) = handle.add_watch_path(&path) {
+                self.broadcaster.warn(format!("[{}] {err}", self.name));
+            }
+        }
+    }
+
+    /// Re-create filesystem watcher if its event loop is no longer running (e.g. crashed)
+    ///
+    /// Watch subscribers are kept by the runtime, so they keep receiving events afterwards.
+    fn restart_watcher(&mut self) -> Result<()> {
+        let is_running = self
+            .watcher_handle
+            .as_ref()
+            .map_or(false, |handle| !handle.is_finished());
+        if is_running {
+            return Ok(());
+        }
+
+        tracing::warn!("Watcher is not running, restarting");
+        self.spawn_watcher()
+    }
+
+    /// Regenerate ignore patterns (i.e. after .gitignore changes) and apply them to the watcher
+    async fn refresh_watchignore(&mut self) -> Result<()> {
+        let patterns = project::generate_watchignore(self.project.root()).await;
+        if let Some(ref handle) = self.watcher_handle {
+            handle.set_ignore_patterns(&patterns)?;
+        }
+        *self.project.watchignore_mut() = patterns;
+        info!("Updated ignore patterns");
+        Ok(())
+    }
+
+    async fn on_connect(&mut self, id: u32) {
         info!("Connected [{id}]");
         self.clients += 1;
         let msg = format!("[{}] Registered", self.name);
         self.broadcaster.info_with_id(id, msg);
-        self.set_client_project_state(Some(id));
+        self.set_client_project_state(Some(id)).await;
         self.set_client_runner_state(id);
     }
 
     #[instrument(parent = None, name = "FSWatcher", skip_all, fields(name = self.name))]
     async fn on_fs_event(&mut self, event: Event) {
+        info!("Processing {event}");
+        if event.file_name() == ".gitignore" {
+            if event.path() == &self.project.root().join(".gitignore") {
+                if let Err(e) = self.refresh_watchignore().await {
+                    self.broadcaster.error(format!("[{}] {e}", self.name));
+                }
+            }
+            return;
+        }
+
         let name = &self.name;
 
-        info!("Processing {event}");
         if event.is_create_event()
             || event.is_remove_event()
             || event.is_content_update_event()
@@ -121,7 +194,10 @@ impl ProjectRuntime {
             let ensure_setup = self.project.ensure_setup(Some(&event), &self.broadcaster);
             match ensure_setup.await {
                 Err(e) => self.broadcaster.error(format!("[{name}] {e}")),
-                Ok(true) => self.set_client_project_state(None),
+                Ok(true) => {
+                    self.set_client_project_state(None).await;
+                    self.watch_additional_paths();
+                }
                 _ => {}
             };
         }
@@ -133,6 +209,7 @@ impl ProjectRuntime {
         info!("Processed {event}");
 
         self.watcher_state.update_debounce();
+        self.watcher_state.update_path_debounce(event.path());
     }
 
     #[instrument(parent = None, name = "FSWatcher", skip_all, fields(name = self.name))]
@@ -150,10 +227,17 @@ impl ProjectRuntime {
         if let Err(err) = res.await {
             let msg = format!("[{}] failed to start runner {err}", self.name);
             self.broadcaster.error(msg);
+            self.broadcaster
+                .update_statusline(TaskStatus::error(err.to_string()));
         }
         info!("Ran {}", service.settings.target);
         if is_watch {
-            self.watcher_subscribers.add(service);
+            if let Err(err) = self.restart_watcher() {
+                self.broadcaster.error(format!("[{}] {err}", self.name));
+            }
+            if let Err(err) = self.watcher_subscribers.add(service) {
+                self.broadcaster.error(format!("[{}] {err}", self.name));
+            }
         }
     }
 
@@ -167,22 +251,45 @@ impl ProjectRuntime {
         };
 
         info!("Building {}", req.settings.target);
+        if is_watch {
+            if let Err(err) = self.restart_watcher() {
+                self.broadcaster.error(format!("[{}] {err}", self.name));
+            }
+            let subscribers = &mut self.watcher_subscribers;
+            match self
+                .project
+                .watch_build(req, &self.broadcaster, subscribers)
+            {
+                Ok(handle) => info!("Watching {}", handle.key()),
+                Err(err) => self.broadcaster.error(format!("[{}] {err}", self.name)),
+            }
+            return;
+        }
+
         let event = Event::default();
         let res = req.trigger(&mut self.project, &event, &self.broadcaster);
         if let Err(err) = res.await {
             let msg = format!("[{}] failed to start runner {err}", self.name);
             self.broadcaster.error(msg);
+            self.broadcaster
+                .update_statusline(TaskStatus::error(err.to_string()));
         }
         info!("Built {}", req.settings.target);
-        if is_watch {
-            self.watcher_subscribers.add(req);
-        }
     }
 
-    fn set_client_project_state(&mut self, id: Option<u32>) {
+    async fn set_client_project_state(&mut self, id: Option<u32>) {
         let info = ProjectInfo {
             watchlist: self.watcher_subscribers.keys(),
             targets: self.project.targets().clone(),
+            configurations: self
+                .project
+                .configuration_names()
+                .into_iter()
+                .map(ToString::to_string)
+                .collect(),
+            has_uncommitted_changes: self.project.has_uncommitted_changes().await,
+            adjacent_xcode_project: self.project.adjacent_xcode_project().cloned(),
+            is_multi_platform: self.project.is_multi_platform(),
         };
         self.broadcaster.set_state(id, State::ProjectInfo(info))
     }
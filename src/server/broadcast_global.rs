@@ -0,0 +1,20 @@
+use super::*;
+use crate::*;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// Notify clients of all registered projects (e.g. daemon upgrade or global error)
+#[derive(Debug, Serialize, Deserialize, TypeDef)]
+pub struct BroadcastGlobalRequest {
+    pub content: String,
+    pub level: ContentLevel,
+}
+
+#[async_trait]
+impl RequestHandler<()> for BroadcastGlobalRequest {
+    async fn handle(self) -> Result<()> {
+        let BroadcastGlobalRequest { content, level } = self;
+        broadcast_to_all(Message::Notify { content, level }).await;
+        Ok(())
+    }
+}
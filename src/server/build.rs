@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display};
 use std::{path::PathBuf, sync::Arc};
+use tokio::sync::mpsc::Receiver;
 use {super::*, crate::*};
 
 /// Request to build a particular project
@@ -32,9 +33,18 @@ impl Display for BuildRequest {
 
 #[async_trait]
 impl Watchable for BuildRequest {
-    async fn trigger(&self, p: &mut ProjectImpl, _: &Event, b: &Arc<Broadcast>) -> Result<()> {
-        p.build(&self.settings, None, b)?;
-        Ok(())
+    async fn trigger(
+        &self,
+        p: &mut ProjectImpl,
+        _: &Event,
+        b: &Arc<Broadcast>,
+    ) -> Result<Option<Receiver<bool>>> {
+        let (_, recv) = if self.settings.scheme.is_some() {
+            p.build_scheme(&self.settings, None, b)?
+        } else {
+            p.build(&self.settings, None, b)?
+        };
+        Ok(Some(recv))
     }
 
     /// A function that controls whether a a Watchable should restart
@@ -48,5 +58,16 @@ impl Watchable for BuildRequest {
     }
 
     /// Drop watchable for watching a given file system
-    async fn discard(&self) {}
+    async fn discard(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn metadata(&self) -> serde_json::Value {
+        serde_json::json!({
+            "kind": "build",
+            "root": self.root,
+            "settings": self.settings,
+            "operation": self.operation,
+        })
+    }
 }
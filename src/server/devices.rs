@@ -0,0 +1,17 @@
+use super::*;
+use crate::*;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// Get available devices to run built projects with
+#[derive(Debug, Serialize, Deserialize, TypeDef)]
+pub struct DevicesRequest {}
+
+#[async_trait]
+impl RequestHandler<Vec<Device>> for DevicesRequest {
+    async fn handle(self) -> Result<Vec<Device>> {
+        tokio::task::spawn_blocking(Devices::list)
+            .await
+            .map_err(|e| Error::JoinError(e.to_string()))?
+    }
+}
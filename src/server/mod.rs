@@ -1,5 +1,9 @@
+mod broadcast_global;
 mod build;
+mod devices;
 mod drop;
+mod open_xcode;
+mod readme;
 mod register;
 mod request;
 mod response;
@@ -12,7 +16,10 @@ use tokio_util::codec::{BytesCodec, FramedRead, FramedWrite};
 use tracing::instrument;
 use typescript_type_def::TypeDef;
 
-pub use {build::*, drop::*, register::*, request::*, response::*, run::*};
+pub use {
+    broadcast_global::*, build::*, devices::*, drop::*, open_xcode::*, readme::*, register::*,
+    request::*, response::*, run::*,
+};
 
 /// Stream of Requests to read Requests from
 struct RequestStream;
@@ -0,0 +1,23 @@
+use super::*;
+use crate::runtime::PRMessage;
+use crate::*;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Request to reveal README of a registered project in clients
+#[derive(Debug, Serialize, Deserialize, TypeDef)]
+pub struct ReadmeRequest {
+    pub root: PathBuf,
+}
+
+#[async_trait]
+impl RequestHandler<()> for ReadmeRequest {
+    async fn handle(self) -> Result<()> {
+        runtimes()
+            .await
+            .get(&self.root)
+            .ok_or_else(|| Error::UnknownProject(self.root.clone()))
+            .map(|r| r.send(PRMessage::RevealReadme))
+    }
+}
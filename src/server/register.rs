@@ -32,7 +32,15 @@ impl RequestHandler<PathBuf> for RegisterRequest {
                 runtimes.get_mut(&root).unwrap().connect(id);
             });
 
-            return Ok(address);
+            return Ok(address.into());
+        }
+
+        if runtimes.len() >= *MAX_PROJECTS {
+            tracing::error!(
+                "Refusing to register {root:?}, {} projects registered",
+                runtimes.len()
+            );
+            return Err(Error::ProjectLimitReached);
         }
 
         let (rloop, mut runtime) = match ProjectRuntime::new(root.clone()).await {
@@ -53,6 +61,6 @@ impl RequestHandler<PathBuf> for RegisterRequest {
             rloop.start(id).await;
         });
 
-        Ok(address)
+        Ok(address.into())
     }
 }
@@ -14,6 +14,14 @@ pub enum Request {
     Run(RunRequest),
     /// Drop projects at a given roots
     Drop(DropRequest),
+    /// Notify clients of all registered projects
+    BroadcastGlobal(BroadcastGlobalRequest),
+    /// Get available devices
+    Devices(DevicesRequest),
+    /// Open project in Xcode
+    OpenXcode(OpenXcodeRequest),
+    /// Reveal project README
+    Readme(ReadmeRequest),
 }
 
 impl Request {
@@ -23,6 +31,10 @@ impl Request {
             Request::Build(req) => req.handle().await.pipe(Response::new),
             Request::Run(req) => req.handle().await.pipe(Response::new),
             Request::Drop(req) => req.handle().await.pipe(Response::new),
+            Request::BroadcastGlobal(req) => req.handle().await.pipe(Response::new),
+            Request::Devices(req) => req.handle().await.pipe(Response::new),
+            Request::OpenXcode(req) => req.handle().await.pipe(Response::new),
+            Request::Readme(req) => req.handle().await.pipe(Response::new),
         }
     }
 }
@@ -1,18 +1,31 @@
 use crate::error::*;
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, fmt::Display};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+    time::Duration,
+};
 use strum::{Display as EnumDisplay, EnumString};
 use typescript_type_def::TypeDef;
-use xcodeproj::pbxproj::PBXTargetInfo;
+use xcodeproj::pbxproj::{PBXTargetInfo, PBXTargetPlatform};
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
-#[derive(Debug, Serialize, TypeDef)]
+#[derive(Debug, Clone, Serialize, TypeDef)]
 pub struct ProjectInfo {
     /// Get watched configurations for given root
     pub watchlist: Vec<String>,
     /// Get targets information for a registers project with a given root
     pub targets: HashMap<String, TargetInfo>,
+    /// Configuration names across all targets
+    pub configurations: Vec<String>,
+    /// Whether the project git working tree has uncommitted changes
+    pub has_uncommitted_changes: bool,
+    /// Xcode project found at the root of a swift package
+    pub adjacent_xcode_project: Option<PathBuf>,
+    /// Whether targets span more than one platform, i.e. clients should ask for a platform
+    pub is_multi_platform: bool,
 }
 
 /// Type of operation for building/ruuning a target/scheme
@@ -26,30 +39,300 @@ pub enum Operation {
 /// Build Settings used in building/running a target/scheme
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TypeDef)]
 pub struct BuildSettings {
-    /// Target to build
+    /// Target to build, may be omitted when building a scheme
+    #[serde(default)]
     pub target: String,
     /// Configuration to build with, default Debug
     pub configuration: String,
     /// Scheme to build with
     pub scheme: Option<String>,
+    /// Arguments to pass to the running application
+    #[serde(default)]
+    pub launch_args: Vec<String>,
+    /// Environment variables to set for the running application
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+    /// Code signing identity used for distribution builds
+    #[serde(default)]
+    pub signing_identity: Option<String>,
+    /// Environment variables to set while building, e.g. `XCODE_XCCONFIG_FILE`
+    #[serde(default)]
+    pub env_vars: HashMap<String, String>,
+    /// Seconds after which building gets killed and fails, no timeout by default
+    #[serde(default)]
+    pub timeout: Option<u64>,
+}
+
+impl BuildSettings {
+    /// Key fragments marking an environment variable value as secret
+    const SECRET_MARKERS: [&'static str; 3] = ["SECRET", "TOKEN", "KEY"];
+
+    /// Build environment variables formatted as `KEY=VALUE`, sorted and with secret values
+    /// redacted, safe to be logged.
+    #[must_use]
+    pub fn redacted_env_vars(&self) -> Vec<String> {
+        let mut vars = self
+            .env_vars
+            .iter()
+            .map(|(key, value)| {
+                let upper = key.to_uppercase();
+                if Self::SECRET_MARKERS.iter().any(|m| upper.contains(m)) {
+                    format!("{key}=****")
+                } else {
+                    format!("{key}={value}")
+                }
+            })
+            .collect::<Vec<_>>();
+        vars.sort();
+        vars
+    }
+
+    /// Estimate how long building the target might take based on its source count
+    ///
+    /// NOTE: only advisory, clean builds may need to resolve dependencies first
+    #[must_use]
+    pub fn estimated_build_time(&self, info: &TargetInfo) -> Duration {
+        let per_source = Duration::from_millis(500) * info.estimated_source_count() as u32;
+        Duration::from_secs(60) + per_source
+    }
+
+    /// Duration after which building gets killed, see [`BuildSettings::timeout`]
+    #[must_use]
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout.map(Duration::from_secs)
+    }
 }
 
 /// Target specfic information
 #[derive(Clone, Debug, Serialize, Deserialize, TypeDef)]
 pub struct TargetInfo {
-    pub platform: String,
+    pub platform: Platform,
     pub configurations: Vec<String>,
+    /// Host application the target tests run inside of (Xcode TEST_HOST/BUNDLE_LOADER)
+    #[serde(default)]
+    pub test_host: Option<String>,
+    /// Cached number of source files, see [`TargetInfo::estimated_source_count`]
+    #[serde(default)]
+    pub source_count: Option<usize>,
+    /// Kind of product the target produces, only known for swift packages
+    #[serde(default)]
+    pub kind: Option<TargetKind>,
+    /// Names of targets the target depends on
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+}
+
+/// Kind of product a target produces
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, EnumDisplay, TypeDef)]
+pub enum TargetKind {
+    Executable,
+    Library,
+    Test,
+    Plugin,
+}
+
+impl TargetKind {
+    /// Get target kind from swift package target type, e.g. `executable` or `regular`
+    pub fn from_package_type(kind: &str) -> Self {
+        match kind {
+            "executable" | "snippet" => Self::Executable,
+            "test" => Self::Test,
+            "plugin" => Self::Plugin,
+            _ => Self::Library,
+        }
+    }
+
+    /// Returns `true` if the target kind is [`TargetKind::Executable`].
+    #[must_use]
+    pub fn is_executable(&self) -> bool {
+        matches!(self, Self::Executable)
+    }
 }
 
 impl From<PBXTargetInfo> for TargetInfo {
     fn from(info: PBXTargetInfo) -> Self {
         Self {
-            platform: info.platform.to_string(),
+            platform: info.platform.into(),
             configurations: info.configurations,
+            test_host: None,
+            source_count: None,
+            kind: None,
+            dependencies: vec![],
         }
     }
 }
 
+impl TargetInfo {
+    /// Returns `true` if the target tests require a host application to be installed first.
+    #[must_use]
+    pub fn is_test_host_app_capable(&self) -> bool {
+        self.test_host
+            .as_ref()
+            .map(|host| !host.is_empty())
+            .unwrap_or_default()
+    }
+
+    /// Estimated number of source files the target has, 0 if it was never counted.
+    #[must_use]
+    pub fn estimated_source_count(&self) -> usize {
+        self.source_count.unwrap_or_default()
+    }
+
+    /// Count and cache swift source files under `<root>/Sources/<name>`
+    pub fn with_source_count(mut self, root: &Path, name: &str) -> Self {
+        let sources = root.join("Sources").join(name);
+        self.source_count = Some(crate::util::fs::count_files_with_extension(
+            sources, "swift",
+        ));
+        self
+    }
+}
+
+/// Platform a target is built for or a device runs
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    EnumDisplay,
+    EnumString,
+    TypeDef,
+)]
+pub enum Platform {
+    #[serde(rename = "iOS")]
+    #[strum(serialize = "iOS")]
+    IOS,
+    #[serde(rename = "watchOS")]
+    #[strum(serialize = "watchOS")]
+    WatchOS,
+    #[serde(rename = "tvOS")]
+    #[strum(serialize = "tvOS")]
+    TvOS,
+    #[serde(rename = "macOS")]
+    #[strum(serialize = "macOS")]
+    MacOS,
+    #[serde(rename = "visionOS")]
+    #[strum(serialize = "visionOS")]
+    VisionOS,
+    Unknown,
+}
+
+impl Platform {
+    /// Get platform from simulator runtime identifier,
+    /// e.g. `com.apple.CoreSimulator.SimRuntime.xrOS-1-0`
+    pub fn from_identifer(identifier: &str) -> Result<Self, String> {
+        let runtime = identifier.rsplit('.').next().unwrap_or_default();
+        let (name, _version) = runtime
+            .split_once('-')
+            .ok_or_else(|| format!("Invalid runtime identifier `{identifier}`"))?;
+
+        match name {
+            "xrOS" => Ok(Self::VisionOS),
+            name => name
+                .parse()
+                .map_err(|_| format!("Unknown platform `{name}` in `{identifier}`")),
+        }
+    }
+
+    /// Get platform from swift package platform name, e.g. `macos` or `visionos`
+    pub fn from_package_name(name: &str) -> Self {
+        match name.to_lowercase().as_str() {
+            "ios" => Self::IOS,
+            "watchos" => Self::WatchOS,
+            "tvos" => Self::TvOS,
+            "macos" => Self::MacOS,
+            "visionos" => Self::VisionOS,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// Get platform from SDKROOT or PLATFORM_NAME value, e.g. `iphonesimulator` or path to
+    /// `XRSimulator.sdk`
+    pub fn from_sdk_root(sdk_root: &str) -> Self {
+        let sdk = Path::new(sdk_root)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+
+        if sdk.starts_with("iphone") {
+            Self::IOS
+        } else if sdk.starts_with("watch") {
+            Self::WatchOS
+        } else if sdk.starts_with("appletv") {
+            Self::TvOS
+        } else if sdk.starts_with("macosx") {
+            Self::MacOS
+        } else if sdk.starts_with("xr") {
+            Self::VisionOS
+        } else {
+            Self::Unknown
+        }
+    }
+
+    /// Get platform from build settings, preferring `PLATFORM_NAME` over `SDKROOT`
+    ///
+    /// Returns [`Platform::Unknown`] when both are missing, empty or unrecognized.
+    pub fn get_from_settings(settings: &HashMap<String, String>) -> Self {
+        ["PLATFORM_NAME", "SDKROOT"]
+            .iter()
+            .flat_map(|key| settings.get(*key))
+            .map(|value| Self::from_sdk_root(value))
+            .find(|platform| *platform != Self::Unknown)
+            .unwrap_or_default()
+    }
+
+    /// Same as [`Platform::get_from_settings`] but falls back to [`Platform::MacOS`]
+    pub fn from_settings_or_default(settings: &HashMap<String, String>) -> Self {
+        match Self::get_from_settings(settings) {
+            Self::Unknown => Self::MacOS,
+            platform => platform,
+        }
+    }
+
+    /// Returns `true` if the platform is [`Platform::VisionOS`].
+    #[must_use]
+    pub fn is_vision_os(&self) -> bool {
+        matches!(self, Self::VisionOS)
+    }
+}
+
+impl From<PBXTargetPlatform> for Platform {
+    fn from(platform: PBXTargetPlatform) -> Self {
+        match platform {
+            PBXTargetPlatform::IOS => Self::IOS,
+            PBXTargetPlatform::WatchOS => Self::WatchOS,
+            PBXTargetPlatform::TvOS => Self::TvOS,
+            PBXTargetPlatform::MacOS => Self::MacOS,
+            PBXTargetPlatform::Unknown => Self::Unknown,
+        }
+    }
+}
+
+impl From<Platform> for PBXTargetPlatform {
+    fn from(platform: Platform) -> Self {
+        match platform {
+            Platform::IOS => Self::IOS,
+            Platform::WatchOS => Self::WatchOS,
+            Platform::TvOS => Self::TvOS,
+            Platform::MacOS => Self::MacOS,
+            // NOTE: xcodeproj has no visionOS platform yet
+            Platform::VisionOS | Platform::Unknown => Self::Unknown,
+        }
+    }
+}
+
+impl Default for Platform {
+    fn default() -> Self {
+        Self::Unknown
+    }
+}
+
 /// Device Lookup information to run built project with
 #[derive(Clone, Default, Debug, Serialize, Deserialize, TypeDef)]
 pub struct DeviceLookup {
@@ -73,10 +356,12 @@ impl Display for BuildSettings {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "-configuration {}", self.configuration)?;
 
+        // NOTE: scheme takes precedence over target
         if let Some(ref scheme) = self.scheme {
             write!(f, " -scheme {scheme}")?;
+        } else {
+            write!(f, " -target {}", self.target)?;
         }
-        write!(f, " -target {}", self.target)?;
         Ok(())
     }
 }
@@ -116,3 +401,36 @@ impl Operation {
         matches!(self, Self::Once)
     }
 }
+
+#[test]
+fn test_platform_from_identifer() {
+    let from = Platform::from_identifer;
+    assert_eq!(
+        from("com.apple.CoreSimulator.SimRuntime.iOS-16-0"),
+        Ok(Platform::IOS)
+    );
+    assert_eq!(
+        from("com.apple.CoreSimulator.SimRuntime.watchOS-9-0"),
+        Ok(Platform::WatchOS)
+    );
+    assert_eq!(
+        from("com.apple.CoreSimulator.SimRuntime.tvOS-16-0"),
+        Ok(Platform::TvOS)
+    );
+    assert_eq!(
+        from("com.apple.CoreSimulator.SimRuntime.xrOS-1-0"),
+        Ok(Platform::VisionOS)
+    );
+    assert!(from("").is_err());
+    assert!(from("com.apple.CoreSimulator.SimRuntime.iOS").is_err());
+    assert!(from("com.apple.CoreSimulator.SimRuntime.fooOS-1-0").is_err());
+}
+
+#[test]
+fn test_platform_from_package_name() {
+    let from = Platform::from_package_name;
+    assert_eq!(from("ios"), Platform::IOS);
+    assert_eq!(from("macos"), Platform::MacOS);
+    assert_eq!(from("visionOS"), Platform::VisionOS);
+    assert_eq!(from("linux"), Platform::Unknown);
+}
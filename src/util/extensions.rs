@@ -2,20 +2,34 @@ use crate::{Error, Result};
 use std::path::Path;
 
 pub trait PathExt {
+    /// Get file name with the first letter capitalized, intended for display only.
     fn name(&self) -> Option<String>;
+    /// Get unmodified file name.
+    fn raw_name(&self) -> Option<&str>;
     fn unique_name(&self) -> Option<String>;
     fn abbrv(&self) -> Result<&Path>;
+    /// Get path relative to base, or `None` if base isn't a prefix of path.
+    fn relative_to(&self, base: &Path) -> Option<&Path>;
+    /// Get path relative to base, or path itself if base isn't a prefix of path.
+    fn relative_to_or_self(&self, base: &Path) -> &Path;
 }
 
 impl PathExt for Path {
     fn name(&self) -> Option<String> {
         let mut name = self.file_name().and_then(|os| os.to_str())?.to_string();
+        if name.is_empty() {
+            return None;
+        }
 
         let name = name.remove(0).to_uppercase().to_string() + &name;
 
         Some(name)
     }
 
+    fn raw_name(&self) -> Option<&str> {
+        self.file_name().and_then(|os| os.to_str())
+    }
+
     fn unique_name(&self) -> Option<String> {
         Some(
             self.strip_prefix(self.ancestors().nth(3)?)
@@ -33,4 +47,23 @@ impl PathExt for Path {
         self.strip_prefix(ancestors)
             .map_err(|e| Error::Unexpected(e.to_string()))
     }
+
+    fn relative_to(&self, base: &Path) -> Option<&Path> {
+        self.strip_prefix(base).ok()
+    }
+
+    fn relative_to_or_self(&self, base: &Path) -> &Path {
+        self.relative_to(base).unwrap_or(self)
+    }
+}
+
+#[test]
+fn test_path_name() {
+    assert_eq!(Path::new("/").name(), None);
+    assert_eq!(Path::new("").name(), None);
+    assert_eq!(Path::new("..").name(), None);
+    assert_eq!(Path::new("/foo/").name(), Some("Foo".into()));
+    assert_eq!(Path::new("/foo/.").name(), Some("Foo".into()));
+    assert_eq!(Path::new("/foo/...").name(), Some("...".into()));
+    assert_eq!(Path::new("/foo/bar.swift").name(), Some("Bar.swift".into()));
 }
@@ -15,5 +15,9 @@ pub fn as_section(content: String) -> String {
 }
 
 pub fn separator() -> String {
-    ".".repeat(73)
+    separator_with('.', 73)
+}
+
+pub fn separator_with(ch: char, length: usize) -> String {
+    ch.to_string().repeat(length)
 }
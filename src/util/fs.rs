@@ -1,7 +1,7 @@
 //! Functions to query/access filesystem
 use crate::BuildSettings;
 use anyhow::Result;
-use std::{fmt::Debug, path::Path};
+use std::{fmt::Debug, path::Path, time::Duration};
 use tap::Pipe;
 use tokio::fs;
 
@@ -70,6 +70,64 @@ pub fn which(cmd: &str) -> Result<String> {
     Ok(which::which(cmd)?.to_str().unwrap().to_string())
 }
 
+/// Check whether the git working tree at root has uncommitted changes.
+///
+/// Returns `None` if root is not a git repository or `git status` took longer than timeout.
+/// `git` is killed when it exceeds timeout.
+pub async fn git_has_uncommitted_changes<P: AsRef<Path>>(
+    root: P,
+    timeout: Duration,
+) -> Option<bool> {
+    use std::process::Stdio;
+    use tokio::process::Command;
+
+    let child = Command::new("git")
+        .args(["status", "--porcelain"])
+        .current_dir(root)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .kill_on_drop(true)
+        .spawn()
+        .ok()?;
+
+    let output = tokio::time::timeout(timeout, child.wait_with_output())
+        .await
+        .ok()?
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(!output.stdout.iter().all(u8::is_ascii_whitespace))
+}
+
+/// Recursively count files with a given extension under dir, returns 0 if dir doesn't exist.
+pub fn count_files_with_extension<P: AsRef<Path>>(dir: P, extension: &str) -> usize {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return 0,
+    };
+
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .map(|path| {
+            if path.is_dir() {
+                count_files_with_extension(&path, extension)
+            } else if path
+                .extension()
+                .map(|ext| ext == extension)
+                .unwrap_or_default()
+            {
+                1
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
 /// Read .gitignore from root and return vec of glob patterns if the .gitignore eixists.
 pub async fn gitignore_to_glob_patterns<P: AsRef<Path>>(path: P) -> Result<Vec<String>> {
     let gitignore_path = path.as_ref().join(".gitignore");
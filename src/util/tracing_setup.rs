@@ -4,15 +4,153 @@ use tracing::dispatcher::SetGlobalDefaultError;
 use tracing::subscriber::set_global_default;
 use tracing::Level;
 use tracing_appender::rolling;
-use tracing_subscriber::fmt::Layer;
+use tracing_subscriber::fmt::{Layer, MakeWriter};
 use tracing_subscriber::prelude::__tracing_subscriber_SubscriberExt;
 use tracing_subscriber::{registry, EnvFilter};
 
+/// Log file output format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Human readable text
+    Text,
+    /// Structured json, one object per line
+    #[cfg(feature = "json")]
+    Json,
+}
+
+impl Default for Format {
+    fn default() -> Self {
+        Self::Text
+    }
+}
+
+/// Log file rotation policy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RollingPolicy {
+    /// Single file growing without bound
+    Never,
+    /// New file every day, suffixed with date
+    Daily,
+    /// New file every hour, suffixed with date and hour
+    Hourly,
+    /// Move log file to `<file>.1` on setup if it exceeds the given number of bytes
+    SizeBased(u64),
+}
+
+impl Default for RollingPolicy {
+    fn default() -> Self {
+        Self::Never
+    }
+}
+
+impl RollingPolicy {
+    fn appender(&self, root: &Path, filename: &str) -> rolling::RollingFileAppender {
+        match self {
+            Self::Never => rolling::never(root, filename),
+            Self::Daily => rolling::daily(root, filename),
+            Self::Hourly => rolling::hourly(root, filename),
+            Self::SizeBased(max_size) => {
+                // NOTE: tracing-appender has no size based rotation, so only rotate on startup
+                let path = root.join(filename);
+                let size = std::fs::metadata(&path)
+                    .map(|m| m.len())
+                    .unwrap_or_default();
+                if size > *max_size {
+                    std::fs::rename(&path, root.join(format!("{filename}.1"))).ok();
+                }
+                rolling::never(root, filename)
+            }
+        }
+    }
+}
+
+/// Writer injecting a `trace_id` field into every formatted log line
+struct TraceIdWriter<M> {
+    inner: M,
+    /// Bytes written before each line, or after the opening brace of json lines
+    field: Option<Vec<u8>>,
+    format: Format,
+}
+
+impl<M> TraceIdWriter<M> {
+    fn new(inner: M, trace_id: Option<&str>, format: Format) -> Self {
+        let field = trace_id.map(|id| match format {
+            Format::Text => format!("trace_id={id} ").into_bytes(),
+            #[cfg(feature = "json")]
+            Format::Json => {
+                let id = serde_json::Value::from(id);
+                format!("\"trace_id\":{id},").into_bytes()
+            }
+        });
+        Self {
+            inner,
+            field,
+            format,
+        }
+    }
+}
+
+impl<'a, M: MakeWriter<'a>> MakeWriter<'a> for TraceIdWriter<M> {
+    type Writer = TraceIdLine<'a, M::Writer>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        TraceIdLine {
+            inner: self.inner.make_writer(),
+            field: self.field.as_deref(),
+            format: self.format,
+        }
+    }
+}
+
+/// Single log line writer created by [`TraceIdWriter`]
+struct TraceIdLine<'a, W> {
+    inner: W,
+    field: Option<&'a [u8]>,
+    format: Format,
+}
+
+impl<W: io::Write> io::Write for TraceIdLine<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let field = match self.field {
+            Some(field) => field,
+            None => return self.inner.write(buf),
+        };
+        // NOTE: each event is formatted into a single buffer before being written
+        let mut line = Vec::with_capacity(field.len() + buf.len());
+        match self.format {
+            Format::Text => {
+                line.extend_from_slice(field);
+                line.extend_from_slice(buf);
+            }
+            #[cfg(feature = "json")]
+            Format::Json => match buf.split_first() {
+                Some((b'{', rest)) => {
+                    line.push(b'{');
+                    line.extend_from_slice(field);
+                    line.extend_from_slice(rest);
+                }
+                _ => line.extend_from_slice(buf),
+            },
+        }
+        self.inner.write_all(&line)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
 /// Setup tracing
+///
+/// When `trace_id` is given, it is added to every log line to correlate logs across services.
 pub fn setup(
     path: impl AsRef<Path>,
     default_level: Level,
     with_stdout: bool,
+    format: Format,
+    rolling: RollingPolicy,
+    trace_id: Option<String>,
 ) -> Result<(), SetGlobalDefaultError> {
     let path = path.as_ref();
     let root = path.parent().unwrap();
@@ -23,7 +161,11 @@ pub fn setup(
         .add_directive("tokio_util=warn".parse().unwrap());
 
     let fmt_file = Layer::new()
-        .with_writer(rolling::never(root, filename))
+        .with_writer(TraceIdWriter::new(
+            rolling.appender(root, filename),
+            trace_id.as_deref(),
+            format,
+        ))
         .with_target(false)
         .with_file(false)
         .without_time()
@@ -31,8 +173,17 @@ pub fn setup(
         .with_thread_ids(false);
     // .with_ansi(false)
     // .compact();
+    let fmt_file = match format {
+        Format::Text => tracing_subscriber::Layer::boxed(fmt_file),
+        #[cfg(feature = "json")]
+        Format::Json => tracing_subscriber::Layer::boxed(fmt_file.json()),
+    };
     let fmt_stdout = Layer::new()
-        .with_writer(io::stdout)
+        .with_writer(TraceIdWriter::new(
+            io::stdout,
+            trace_id.as_deref(),
+            Format::Text,
+        ))
         .with_target(false)
         .with_line_number(true)
         // .without_time()
@@ -49,5 +200,38 @@ pub fn setup(
     } else {
         set_global_default(registry().with(default_filter).with(fmt_file))?
     }
+
+    tracing::info!(
+        "hostname={} process={} pid={} version={}",
+        hostname(),
+        process_name(),
+        std::process::id(),
+        env!("CARGO_PKG_VERSION")
+    );
+
     Ok(())
 }
+
+/// Get machine hostname from `HOSTNAME` or falling back to `hostname` command
+fn hostname() -> String {
+    std::env::var("HOSTNAME")
+        .ok()
+        .filter(|name| !name.is_empty())
+        .or_else(|| {
+            let output = std::process::Command::new("hostname").output().ok()?;
+            Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        })
+        .unwrap_or_else(|| "unknown".into())
+}
+
+/// Get current process executable name
+fn process_name() -> String {
+    std::env::args()
+        .next()
+        .and_then(|arg0| {
+            Path::new(&arg0)
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+        })
+        .unwrap_or_else(|| "unknown".into())
+}
@@ -3,20 +3,35 @@ use super::WatcherState;
 use notify::{Event as NotifyEvent, EventKind as NotifyEventKind};
 use std::{
     fmt,
-    path::PathBuf,
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
     sync::{Arc, Mutex},
+    time::SystemTime,
 };
 use wax::Any;
 
+/// File names of known project configuration files
+static CONFIG_FILES: [&str; 4] = ["Package.swift", "project.yml", "Tuist.swift", ".xbaserc"];
+
+/// Filesystem event relevant to a project
+///
+/// An event is *seen* once its path matches the last processed path (see [`Event::is_seen`]),
+/// which dedupes bursts of events editors emit for a single save. Renames are tricky: notify
+/// reports both the disappearing source and the new destination, so a rename should only lead
+/// to a recompile when the source path no longer exists and wasn't already processed, see
+/// [`Event::is_any_but_not_seen`].
 #[derive(Default, Debug)]
 pub struct Event {
     path: PathBuf,
+    /// All event paths, i.e. source and destination of rename events
+    paths: Vec<PathBuf>,
     file_name: String,
     kind: EventKind,
     last_path: Arc<Mutex<PathBuf>>,
+    created_at: Option<SystemTime>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum EventKind {
     None,
     FileCreated,
@@ -28,6 +43,18 @@ pub enum EventKind {
     Other(NotifyEventKind),
 }
 
+/// Filter deciding whether an event path should be ignored.
+pub trait IgnorePattern: Send + Sync {
+    /// Returns `true` if events of the given path should be ignored.
+    fn is_ignored(&self, path: &Path) -> bool;
+}
+
+impl<'t> IgnorePattern for Any<'t> {
+    fn is_ignored(&self, path: &Path) -> bool {
+        wax::Pattern::is_match(self, &*path.to_string_lossy())
+    }
+}
+
 impl Default for EventKind {
     fn default() -> Self {
         Self::None
@@ -35,18 +62,55 @@ impl Default for EventKind {
 }
 
 impl Event {
-    pub fn new<'a>(
-        ignore: &'a Any<'a>,
+    pub fn new(
+        ignore: &dyn IgnorePattern,
         state: &WatcherState,
-        mut event: NotifyEvent,
+        event: NotifyEvent,
     ) -> Option<Self> {
-        use notify::event::{CreateKind, DataChange, ModifyKind, RemoveKind};
-        use NotifyEventKind::*;
+        let event = Self::from_notify(ignore, state.last_path(), event)?;
 
-        if event.paths.len() > 1 {
-            tracing::error!("More than one path! {:#?}", event)
+        // Skip when last run was less then 1 second agot
+        if let Some(last_run) = state.last_run_safe() {
+            let last_run = last_run.as_millis();
+            if !(last_run > 1) {
+                tracing::trace!("Skip [last_run: {last_run}] [{event}]");
+                return None;
+            }
+        }
+
+        Some(event)
+    }
+
+    /// Process multiple raw events at once, checking watcher state only once.
+    pub fn batch(
+        ignore: &dyn IgnorePattern,
+        state: &WatcherState,
+        events: Vec<NotifyEvent>,
+    ) -> Vec<Self> {
+        // Skip when last run was less then 1 second agot
+        if let Some(last_run) = state.last_run_safe() {
+            let last_run = last_run.as_millis();
+            if !(last_run > 1) {
+                tracing::trace!("Skip {} events [last_run: {last_run}]", events.len());
+                return vec![];
+            }
         }
 
+        let last_path = state.last_path();
+        events
+            .into_iter()
+            .flat_map(|event| Self::from_notify(ignore, last_path.clone(), event))
+            .collect()
+    }
+
+    fn from_notify(
+        ignore: &dyn IgnorePattern,
+        last_path: Arc<Mutex<PathBuf>>,
+        event: NotifyEvent,
+    ) -> Option<Self> {
+        use notify::event::{CreateKind, DataChange, ModifyKind, RemoveKind};
+        use NotifyEventKind::*;
+
         let kind = match event.kind {
             Create(CreateKind::File) => EventKind::FileCreated,
             Create(CreateKind::Folder) => EventKind::FolderCreated,
@@ -57,7 +121,8 @@ impl Event {
             kind => EventKind::Other(kind),
         };
 
-        let path = event.paths.swap_remove(0);
+        let paths = event.paths;
+        let path = paths.first()?.clone();
         let file_name = match path.file_name() {
             Some(name) => name.to_string_lossy().to_string(),
             None => {
@@ -66,10 +131,9 @@ impl Event {
             }
         };
 
-        let is_match = wax::Pattern::is_match;
-
-        // Skip ignore paths
-        if is_match(ignore, &*path.to_string_lossy()) {
+        // Skip ignore paths, renames are kept unless both source and destination are ignored
+        // NOTE: .gitignore is kept so that ignore patterns can be updated on changes
+        if file_name != ".gitignore" && paths.iter().all(|path| ignore.is_ignored(path)) {
             tracing::trace!(r#""{file_name}" ignored"#);
             return None;
         }
@@ -80,21 +144,14 @@ impl Event {
             return None;
         }
 
-        let event = Self {
+        Some(Self {
             path,
+            paths,
             file_name,
             kind,
-            last_path: state.last_path(),
-        };
-
-        // Skip when last run was less then 1 second agot
-        let last_run = state.last_run();
-        if !(last_run > 1) {
-            tracing::trace!("Skip [last_run: {last_run}] [{event}]");
-            return None;
-        }
-
-        Some(event)
+            last_path,
+            created_at: Some(SystemTime::now()),
+        })
     }
 
     /// Returns `true` if the watch event kind is [`EventKind::FileUpdated`]
@@ -102,6 +159,10 @@ impl Event {
         matches!(self.kind, EventKind::FileUpdated)
     }
 
+    /// Returns `true` for content update, rename, create and remove events, as well as for any
+    /// other event whose path disappeared and wasn't already processed.
+    ///
+    /// NOTE: [`Event::is_seen`] is only consulted (and updated) when the path no longer exists.
     pub fn is_any_but_not_seen(&self) -> bool {
         self.is_content_update_event()
             || self.is_rename_event()
@@ -119,7 +180,7 @@ impl Event {
     /// Returns `true` if the watch event kind is [`EventKind::FileRemoved`] or
     /// [`EventKind::FolderRemoved`].
     pub fn is_remove_event(&self) -> bool {
-        matches!(self.kind, EventKind::FileRemoved) || matches!(self.kind, EventKind::FolderCreated)
+        matches!(self.kind, EventKind::FileRemoved) || matches!(self.kind, EventKind::FolderRemoved)
     }
 
     /// Returns `true` if the watch event kind is [`EventKind::FileRenamed`].
@@ -127,12 +188,27 @@ impl Event {
         matches!(self.kind, EventKind::FileRenamed)
     }
 
+    /// Returns `true` if the event is a rename of which neither source nor destination exist.
+    pub fn is_stale_rename_event(&self) -> bool {
+        self.is_rename_event() && !self.paths().iter().any(|path| path.exists())
+    }
+
     /// Returns `true` if the watch event kind is [`EventKind::Other`].
     #[must_use]
     pub fn is_other_event(&self) -> bool {
         matches!(self.kind, EventKind::Other(..))
     }
 
+    /// Returns `true` if any of the event paths is a known project configuration file.
+    #[must_use]
+    pub fn affects_config(&self) -> bool {
+        CONFIG_FILES.contains(&self.file_name.as_str())
+            || self.paths().iter().any(|path| {
+                path.file_name()
+                    .map_or(false, |name| CONFIG_FILES.iter().any(|file| name == *file))
+            })
+    }
+
     /// Get a reference to the event's kind.
     #[must_use]
     pub fn kind(&self) -> &EventKind {
@@ -151,7 +227,21 @@ impl Event {
         &self.path
     }
 
-    /// Get the event's is seen.
+    /// Get all event paths, e.g. source and destination of a rename, empty for synthetic events.
+    #[must_use]
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    /// Get the time the event was received at, `None` for synthetic events.
+    #[must_use]
+    pub fn created_at(&self) -> Option<SystemTime> {
+        self.created_at
+    }
+
+    /// Whether the event path is the last processed path, recording it as such otherwise.
+    ///
+    /// `project.yml` events are never considered seen.
     #[must_use]
     pub fn is_seen(&self) -> bool {
         tracing::trace!("{}", self.file_name);
@@ -176,6 +266,21 @@ impl Event {
     }
 }
 
+impl PartialEq for Event {
+    fn eq(&self, other: &Self) -> bool {
+        self.path() == other.path() && self.kind() == other.kind()
+    }
+}
+
+impl Eq for Event {}
+
+impl Hash for Event {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.path().hash(state);
+        self.kind().hash(state);
+    }
+}
+
 impl fmt::Display for Event {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use EventKind::*;
@@ -193,3 +298,73 @@ impl fmt::Display for Event {
         write!(f, "{:?} [{event_name}]", self.file_name)
     }
 }
+
+#[cfg(test)]
+pub(super) fn test_event(kind: EventKind, path: &str, last_path: &Arc<Mutex<PathBuf>>) -> Event {
+    let path = PathBuf::from(path);
+    Event {
+        file_name: path.file_name().unwrap().to_string_lossy().to_string(),
+        paths: vec![path.clone()],
+        path,
+        kind,
+        last_path: last_path.clone(),
+        created_at: None,
+    }
+}
+
+#[test]
+fn test_event_is_seen() {
+    let last_path = Arc::new(Mutex::new(PathBuf::new()));
+    let event = test_event(EventKind::FileRenamed, "/xbase/missing/a.swift", &last_path);
+    assert!(!event.is_seen(), "first occurrence is recorded, not seen");
+    assert!(event.is_seen(), "same path is seen afterwards");
+
+    let other = test_event(EventKind::FileRenamed, "/xbase/missing/b.swift", &last_path);
+    assert!(!other.is_seen());
+    assert!(!event.is_seen(), "last path got replaced by other event");
+
+    let config = test_event(
+        EventKind::FileUpdated,
+        "/xbase/missing/project.yml",
+        &last_path,
+    );
+    assert!(!config.is_seen());
+    assert!(!config.is_seen(), "project.yml is never seen");
+}
+
+#[test]
+fn test_event_rename_is_any_but_not_seen() {
+    let last_path = Arc::new(Mutex::new(PathBuf::new()));
+    let event = test_event(EventKind::FileRenamed, "/xbase/missing/a.swift", &last_path);
+    *last_path.lock().unwrap() = event.path().clone();
+    assert!(event.is_seen());
+    assert!(event.is_any_but_not_seen(), "renames trigger regardless");
+}
+
+#[test]
+fn test_event_missing_path_is_any_but_not_seen() {
+    let last_path = Arc::new(Mutex::new(PathBuf::new()));
+    let event = test_event(EventKind::None, "/xbase/missing/a.swift", &last_path);
+    assert!(
+        event.is_any_but_not_seen(),
+        "source disappeared and not processed yet"
+    );
+    assert!(
+        !event.is_any_but_not_seen(),
+        "source disappeared but already processed"
+    );
+}
+
+#[test]
+fn test_event_existing_path_is_any_but_not_seen() {
+    let last_path = Arc::new(Mutex::new(PathBuf::new()));
+    let root = env!("CARGO_MANIFEST_DIR");
+    let path = format!("{root}/Cargo.toml");
+    let event = test_event(EventKind::None, &path, &last_path);
+    assert!(!event.is_any_but_not_seen(), "path still exists");
+    assert_eq!(
+        *last_path.lock().unwrap(),
+        PathBuf::new(),
+        "is_seen not consulted"
+    );
+}
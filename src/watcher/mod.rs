@@ -2,22 +2,32 @@ mod event;
 
 use crate::*;
 use async_trait::async_trait;
-use std::path::PathBuf;
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
-use std::{sync::Mutex, time::SystemTime};
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, SystemTime};
 use tokio::sync::mpsc::{self, channel, Receiver};
 use tokio::sync::Notify;
-use tracing::{error, info, instrument, warn};
+use tokio::task::JoinHandle;
+use tracing::{error, info, instrument};
 
 pub use event::*;
 
 pub struct Watcher {
     name: String,
+    root: PathBuf,
     state: WatcherState,
     sender: mpsc::UnboundedSender<runtime::PRMessage>,
-    ignore: Vec<String>,
+    ignore: Arc<RwLock<wax::Any<'static>>>,
     abort: Arc<Notify>,
-    root: PathBuf,
+    pending: Arc<AtomicUsize>,
+    /// Minimum quiet period per path before its events are forwarded
+    debounce: Duration,
+    rx: Receiver<notify::Event>,
+    /// Kept alive for as long as events are received, shared to watch additional paths
+    watcher: Arc<Mutex<notify::RecommendedWatcher>>,
 }
 
 impl Watcher {
@@ -28,41 +38,79 @@ impl Watcher {
         abort: &Arc<Notify>,
         root: &PathBuf,
         ignore: &Vec<String>,
-    ) -> Self {
-        Self {
+        debounce: Duration,
+    ) -> Result<Self> {
+        let pending: Arc<AtomicUsize> = Default::default();
+        let (rx, watcher) = Self::get_watcher(root, &pending)?;
+        let ignore = Arc::new(RwLock::new(Self::compile_ignore(ignore)?));
+
+        Ok(Self {
             name: name.clone(),
+            root: root.clone(),
             state: state.clone(),
             sender: sender.clone(),
-            ignore: ignore.clone(),
+            ignore,
             abort: abort.clone(),
-            root: root.clone(),
-        }
+            pending,
+            debounce,
+            rx,
+            watcher: Arc::new(Mutex::new(watcher)),
+        })
     }
 
-    #[instrument(parent = None, name = "FSWatcher", skip_all, fields(name = self.name))]
-    pub async fn start(self) {
-        let (mut rx, _w) = self.get_watcher().unwrap();
-        let watchignore = self.ignore.iter().map(AsRef::as_ref).collect::<Vec<&str>>();
-        let ignore = wax::any::<wax::Glob, _>(watchignore).unwrap();
+    fn compile_ignore(patterns: &[String]) -> Result<wax::Any<'static>> {
+        let to_err = |pattern: String| {
+            move |e: wax::BuildError| crate::Error::InvalidIgnorePattern(pattern, e.to_string())
+        };
+        let globs = patterns
+            .iter()
+            .map(|pattern| {
+                let glob = wax::Glob::new(pattern).map_err(to_err(pattern.clone()))?;
+                Ok(glob.into_owned())
+            })
+            .collect::<Result<Vec<_>>>()?;
 
+        wax::any::<wax::Glob, _>(globs).map_err(to_err(patterns.join(", ")))
+    }
+
+    #[instrument(parent = None, name = "FSWatcher", skip_all, fields(name = self.name))]
+    pub async fn start(mut self) {
         tracing::info!("Watching");
         loop {
             tokio::select! {
                 _ = self.abort.notified() => break,
-                event = rx.recv() => {
+                event = self.rx.recv() => {
                     if event.is_none() { break; }
-                    let event = event.unwrap();
-                    let event = match Event::new(&ignore, &self.state, event) {
-                        Some(e) => e,
-                        None => continue,
+                    let mut events = vec![event.unwrap()];
+                    while let Ok(event) = self.rx.try_recv() {
+                        events.push(event);
+                    }
+                    self.pending.fetch_sub(events.len(), Ordering::AcqRel);
+
+                    let events = {
+                        let ignore = match self.ignore.read() {
+                            Ok(ignore) => ignore,
+                            Err(err) => err.into_inner(),
+                        };
+                        Event::batch(&*ignore, &self.state, events)
                     };
 
-                    // IGNORE EVENTS OF RENAME FOR PATHS THAT NO LONGER EXISTS
-                    if !event.path().exists() && event.is_rename_event() {
-                        tracing::debug!("{} [ignored]", event);
-                        continue;
+                    // STOP WATCHING WHEN PROJECT ROOT ITSELF IS REMOVED (e.g. unmounted volume)
+                    if events.iter().any(|e| e.path() == &self.root && e.is_remove_event()) {
+                        tracing::error!("Project root deleted");
+                        let name = &self.name;
+                        let content = format!("[{name}] Project root deleted; stopping watcher");
+                        let level = ContentLevel::Error;
+                        let message = Message::Notify { content, level };
+                        self.sender.send(PRMessage::Broadcast(message)).ok();
+                        break;
+                    }
+
+                    for event in events {
+                        if self.should_forward(&event) {
+                            self.sender.send(PRMessage::FSEvent(event)).ok();
+                        }
                     }
-                    self.sender.send(PRMessage::FSEvent(event)).ok();
                 }
             }
         }
@@ -70,18 +118,51 @@ impl Watcher {
         tracing::info!("[Dropped]");
     }
 
-    fn get_watcher(&self) -> Result<(Receiver<notify::Event>, impl notify::Watcher)> {
+    /// Whether event should be forwarded to the project runtime
+    fn should_forward(&self, event: &Event) -> bool {
+        // IGNORE EVENTS OF RENAME FOR PATHS THAT NO LONGER EXISTS
+        if event.is_stale_rename_event() {
+            tracing::debug!("{} [ignored]", event);
+            return false;
+        }
+        if self.state.is_debounced(event.path(), self.debounce) {
+            tracing::debug!("{} [debounced]", event);
+            return false;
+        }
+        true
+    }
+
+    /// Spawn event loop, aborted once returned handle is dropped
+    pub fn spawn(self) -> WatcherHandle {
+        WatcherHandle {
+            ignore: self.ignore.clone(),
+            watcher: self.watcher.clone(),
+            pending: self.pending.clone(),
+            inner: tokio::spawn(self.start()),
+        }
+    }
+
+    fn get_watcher(
+        root: &PathBuf,
+        pending: &Arc<AtomicUsize>,
+    ) -> Result<(Receiver<notify::Event>, notify::RecommendedWatcher)> {
         use notify::{Config, RecommendedWatcher, RecursiveMode::Recursive, Watcher};
         let (tx, rx) = channel::<notify::Event>(1);
         let create = <RecommendedWatcher as Watcher>::new;
-        let to_err = |e: notify::Error| crate::Error::Unexpected(e.to_string());
+        let to_err = |e: notify::Error| crate::Error::Watch(e.to_string());
+        // NOTE: tokio Receiver doesn't expose its buffer length, so track it manually
+        let pending = pending.clone();
 
         let mut watcher = create(move |res: notify::Result<notify::Event>| {
-            res.map(|event| tx.blocking_send(event).unwrap()).ok();
+            res.map(|event| {
+                pending.fetch_add(1, Ordering::AcqRel);
+                tx.blocking_send(event).unwrap()
+            })
+            .ok();
         })
         .map_err(to_err)?;
 
-        watcher.watch(&self.root, Recursive).map_err(to_err)?;
+        watcher.watch(root, Recursive).map_err(to_err)?;
         watcher
             .configure(Config::NoticeEvents(true))
             .map_err(to_err)?;
@@ -90,16 +171,71 @@ impl Watcher {
     }
 }
 
+/// Handle to a spawned [`Watcher`] event loop
+pub struct WatcherHandle {
+    inner: JoinHandle<()>,
+    /// Ignore patterns shared with the event loop
+    ignore: Arc<RwLock<wax::Any<'static>>>,
+    /// Underlying watcher feeding the event loop
+    watcher: Arc<Mutex<notify::RecommendedWatcher>>,
+    /// Number of events received but not yet processed by the event loop
+    pending: Arc<AtomicUsize>,
+}
+
+impl WatcherHandle {
+    /// Whether the event loop is no longer running
+    pub fn is_finished(&self) -> bool {
+        self.inner.is_finished()
+    }
+
+    /// Whether there are filesystem events received but not yet processed.
+    pub fn has_pending_events(&self) -> bool {
+        self.pending.load(Ordering::Acquire) > 0
+    }
+
+    /// Replace ignore patterns used by the event loop without restarting it.
+    pub fn set_ignore_patterns(&self, patterns: &[String]) -> Result<()> {
+        let ignore = Watcher::compile_ignore(patterns)?;
+        match self.ignore.write() {
+            Ok(mut current) => *current = ignore,
+            Err(err) => *err.into_inner() = ignore,
+        };
+        Ok(())
+    }
+
+    /// Watch an additional directory (e.g. sibling swift package) besides project root.
+    pub fn add_watch_path(&self, path: &Path) -> Result<()> {
+        use notify::{RecursiveMode::Recursive, Watcher};
+        let mut watcher = match self.watcher.lock() {
+            Ok(watcher) => watcher,
+            Err(err) => err.into_inner(),
+        };
+        watcher
+            .watch(path, Recursive)
+            .map_err(|e| crate::Error::Watch(format!("{path:?}: {e}")))?;
+        tracing::info!("Watching {path:?}");
+        Ok(())
+    }
+}
+
+impl Drop for WatcherHandle {
+    fn drop(&mut self) {
+        self.inner.abort();
+    }
+}
+
 /// Trait to make an object react to filesystem changes.
 #[async_trait]
 pub trait Watchable: ToString + Send + Sync + 'static {
     /// Trigger Restart of Watchable.
+    ///
+    /// Returns a receiver for whether started work succeeded, if it's still in progress.
     async fn trigger(
         &self,
         project: &mut ProjectImpl,
         ev: &Event,
         b: &Arc<Broadcast>,
-    ) -> Result<()>;
+    ) -> Result<Option<Receiver<bool>>>;
 
     /// A function that controls whether a a Watchable should restart
     async fn should_trigger(&self, ev: &Event) -> bool;
@@ -108,13 +244,99 @@ pub trait Watchable: ToString + Send + Sync + 'static {
     async fn should_discard(&self, ev: &Event) -> bool;
 
     /// Drop watchable for watching a given file system
-    async fn discard(&self);
+    async fn discard(&self) -> Result<()>;
+
+    /// Watchable specific state for introspection
+    fn metadata(&self) -> serde_json::Value {
+        serde_json::Value::Null
+    }
 }
 
 #[derive(Default)]
 pub struct WatchSubscribers {
     name: String,
     inner: HashMap<String, Box<(dyn Watchable + Send + Sync + 'static)>>,
+    counters: WatchCounters,
+}
+
+/// Snapshot of watch subscribers activity
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WatchStats {
+    /// Number of times a subscriber got triggered
+    pub trigger_count: u64,
+    /// Number of discarded subscribers
+    pub discard_count: u64,
+    /// Number of failed triggers
+    pub trigger_errors: u64,
+    /// Number of failed discards
+    pub discard_errors: u64,
+    /// Number of processed events
+    pub events_processed: u64,
+    /// Median event processing latency
+    pub latency_p50: Duration,
+    /// 95th percentile event processing latency
+    pub latency_p95: Duration,
+    /// 99th percentile event processing latency
+    pub latency_p99: Duration,
+}
+
+/// Counters backing [`WatchStats`], shared with the event loop
+#[derive(Default, Clone)]
+struct WatchCounters {
+    trigger_count: Arc<AtomicU64>,
+    discard_count: Arc<AtomicU64>,
+    trigger_errors: Arc<AtomicU64>,
+    discard_errors: Arc<AtomicU64>,
+    events_processed: Arc<AtomicU64>,
+    /// Most recent event processing latencies
+    latencies: Arc<Mutex<VecDeque<Duration>>>,
+    /// Whether clients got warned about high latency
+    latency_warned: Arc<AtomicBool>,
+    /// Number of triggered tasks still in progress, not part of [`WatchStats`]
+    in_flight: Arc<AtomicUsize>,
+}
+
+impl WatchCounters {
+    /// Maximum number of latencies kept to compute percentiles
+    const MAX_LATENCIES: usize = 1024;
+
+    fn record_latency(&self, latency: Duration) {
+        #[cfg(feature = "metrics")]
+        metrics::histogram!("xbase_watch_event_latency_seconds", latency.as_secs_f64());
+
+        let mut latencies = match self.latencies.lock() {
+            Ok(latencies) => latencies,
+            Err(err) => err.into_inner(),
+        };
+        if latencies.len() == Self::MAX_LATENCIES {
+            latencies.pop_front();
+        }
+        latencies.push_back(latency);
+    }
+
+    /// Get p50, p95 and p99 of recorded latencies
+    fn latency_percentiles(&self) -> [Duration; 3] {
+        let mut latencies = match self.latencies.lock() {
+            Ok(latencies) => latencies.iter().copied().collect::<Vec<_>>(),
+            Err(err) => err.into_inner().iter().copied().collect::<Vec<_>>(),
+        };
+        if latencies.is_empty() {
+            return Default::default();
+        }
+        latencies.sort_unstable();
+        let at = |p: usize| latencies[(latencies.len() - 1) * p / 100];
+        [at(50), at(95), at(99)]
+    }
+
+    fn all(&self) -> [&Arc<AtomicU64>; 5] {
+        [
+            &self.trigger_count,
+            &self.discard_count,
+            &self.trigger_errors,
+            &self.discard_errors,
+            &self.events_processed,
+        ]
+    }
 }
 
 impl WatchSubscribers {
@@ -122,23 +344,55 @@ impl WatchSubscribers {
         Self {
             name: name.clone(),
             inner: Default::default(),
+            counters: Default::default(),
+        }
+    }
+
+    /// Get a snapshot of subscribers activity
+    pub fn stats(&self) -> WatchStats {
+        let load = |counter: &Arc<AtomicU64>| counter.load(Ordering::Relaxed);
+        let [latency_p50, latency_p95, latency_p99] = self.counters.latency_percentiles();
+        WatchStats {
+            trigger_count: load(&self.counters.trigger_count),
+            discard_count: load(&self.counters.discard_count),
+            trigger_errors: load(&self.counters.trigger_errors),
+            discard_errors: load(&self.counters.discard_errors),
+            events_processed: load(&self.counters.events_processed),
+            latency_p50,
+            latency_p95,
+            latency_p99,
+        }
+    }
+
+    /// Reset subscribers activity counters
+    pub fn reset(&self) {
+        for counter in self.counters.all() {
+            counter.store(0, Ordering::Relaxed);
+        }
+        if let Ok(mut latencies) = self.counters.latencies.lock() {
+            latencies.clear();
         }
+        self.counters.latency_warned.store(false, Ordering::Relaxed);
     }
     #[instrument(parent = None, name = "FSWatcher", skip_all, fields(name = self.name))]
-    pub fn add<W: Watchable>(&mut self, watchable: W) {
+    /// Add watchable, failing if a watchable with the same key is already subscribed
+    pub fn add<W: Watchable>(&mut self, watchable: W) -> Result<()> {
         let key = watchable.to_string();
         if self.inner.contains_key(&key) {
-            warn!("trying to add {key}!!");
-        } else {
-            self.inner.insert(key, Box::new(watchable));
+            return Err(crate::Error::DuplicateWatchable(key));
         }
+        self.inner.insert(key, Box::new(watchable));
+        Ok(())
     }
 
     #[instrument(parent = None, name = "FSWatcher", skip_all, fields(name = self.name))]
     pub async fn remove<S: ToString>(&mut self, t: &S) {
         let key = t.to_string();
         if let Some(w) = self.inner.remove(&key) {
-            w.discard().await;
+            if let Err(err) = w.discard().await {
+                self.counters.discard_errors.fetch_add(1, Ordering::Relaxed);
+                error!("discard errored for `{key}`!: {err}");
+            }
             info!("Removed watch subscriber: `{key}`");
         } else {
             error!("Trying to remove non-existent watch subscriber: `{key}`")
@@ -149,6 +403,54 @@ impl WatchSubscribers {
         self.inner.keys().map(ToString::to_string).collect()
     }
 
+    /// Trigger every subscriber that should trigger on event, without updating counters.
+    #[cfg(test)]
+    pub async fn trigger_all(
+        &mut self,
+        project: &mut ProjectImpl,
+        event: &Event,
+        broadcast: &Arc<Broadcast>,
+    ) -> Vec<Result<Option<Receiver<bool>>>> {
+        let mut results = vec![];
+        for w in self.inner.values() {
+            if w.should_trigger(event).await {
+                results.push(w.trigger(project, event, broadcast).await);
+            }
+        }
+        results
+    }
+
+    /// Get number of registered watch subscribers
+    pub fn listener_count(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if a watch subscriber is registered with the given key
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.inner.contains_key(key)
+    }
+
+    /// Update statusline to idle once all triggered tasks, including previous ones, are finished
+    fn set_idle_when_done(&self, in_progress: Vec<Receiver<bool>>, broadcast: &Arc<Broadcast>) {
+        let in_flight = &self.counters.in_flight;
+        in_flight.fetch_add(in_progress.len(), Ordering::SeqCst);
+        if in_flight.load(Ordering::SeqCst) == 0 {
+            broadcast.update_statusline(TaskStatus::Idle);
+            return;
+        }
+
+        for mut recv in in_progress {
+            let in_flight = in_flight.clone();
+            let broadcast = broadcast.clone();
+            tokio::spawn(async move {
+                recv.recv().await;
+                if in_flight.fetch_sub(1, Ordering::SeqCst) == 1 {
+                    broadcast.update_statusline(TaskStatus::Idle);
+                }
+            });
+        }
+    }
+
     #[instrument(parent = None, name = "FSWatcher", skip_all, fields(name = self.name))]
     pub async fn trigger(
         &mut self,
@@ -157,23 +459,55 @@ impl WatchSubscribers {
         broadcast: &Arc<Broadcast>,
     ) {
         let mut discards = vec![];
+        let mut in_progress = vec![];
+        let counters = &self.counters;
+
+        counters.events_processed.fetch_add(1, Ordering::Relaxed);
 
         for (key, w) in self.inner.iter() {
             if w.should_discard(&event).await {
-                w.discard().await;
+                if let Err(err) = w.discard().await {
+                    counters.discard_errors.fetch_add(1, Ordering::Relaxed);
+                    error!("discard errored for `{key}`!: {err}");
+                }
                 discards.push(key.to_string());
             } else if w.should_trigger(&event).await {
+                counters.trigger_count.fetch_add(1, Ordering::Relaxed);
                 let trigger = w.trigger(project, event, broadcast);
-                if let Err(err) = trigger.await {
-                    error!("trigger errored for `{key}`!: {err}");
+                match trigger.await {
+                    Ok(recv) => in_progress.extend(recv),
+                    Err(err) => {
+                        counters.trigger_errors.fetch_add(1, Ordering::Relaxed);
+                        error!("trigger errored for `{key}`!: {err}");
+                    }
                 }
             }
         }
 
         for key in discards {
             info!("Discarded: `{key}`");
+            self.counters.discard_count.fetch_add(1, Ordering::Relaxed);
             self.inner.remove(&key);
         }
+
+        self.set_idle_when_done(in_progress, broadcast);
+
+        let latency = event.created_at().and_then(|at| at.elapsed().ok());
+        if let Some(latency) = latency {
+            let counters = &self.counters;
+            counters.record_latency(latency);
+
+            let [_, _, p99] = counters.latency_percentiles();
+            let is_slow = p99 > Duration::from_secs(1);
+            let was_slow = counters.latency_warned.swap(is_slow, Ordering::Relaxed);
+            if is_slow && !was_slow {
+                broadcast.warn(format!(
+                    "[{}] Slow filesystem event processing (p99: {}ms)",
+                    self.name,
+                    p99.as_millis()
+                ));
+            }
+        }
     }
 }
 
@@ -181,6 +515,7 @@ impl WatchSubscribers {
 pub struct WatcherState {
     debounce: Arc<Mutex<SystemTime>>,
     last_path: Arc<Mutex<PathBuf>>,
+    path_debounce: Arc<Mutex<HashMap<PathBuf, SystemTime>>>,
 }
 
 impl WatcherState {
@@ -188,6 +523,7 @@ impl WatcherState {
         Self {
             debounce: Arc::new(Mutex::new(SystemTime::now())),
             last_path: Default::default(),
+            path_debounce: Default::default(),
         }
     }
     pub fn update_debounce(&self) {
@@ -196,7 +532,38 @@ impl WatcherState {
         tracing::trace!("Debounce updated!!!");
     }
 
-    pub fn last_run(&self) -> u128 {
+    /// Update debounce of a given path, so that only its own events are suppressed.
+    pub fn update_path_debounce(&self, path: &Path) {
+        if let Ok(mut paths) = self.path_debounce.lock() {
+            paths.insert(path.to_path_buf(), SystemTime::now());
+        }
+    }
+
+    /// Returns `true` if events of path were processed less than duration ago.
+    ///
+    /// A zero duration never debounces.
+    pub fn is_debounced(&self, path: &Path, duration: Duration) -> bool {
+        if duration.is_zero() {
+            return false;
+        }
+        let paths = match self.path_debounce.lock() {
+            Ok(paths) => paths,
+            Err(_) => return false,
+        };
+        paths
+            .get(path)
+            .and_then(|last_run| last_run.elapsed().ok())
+            .map(|elapsed| elapsed < duration)
+            .unwrap_or_default()
+    }
+
+    /// Get time elapsed since last debounce update, or `None` if the lock is poisoned.
+    pub fn last_run_safe(&self) -> Option<Duration> {
+        self.debounce.lock().ok()?.elapsed().ok()
+    }
+
+    #[deprecated = "use `last_run_safe` instead"]
+    pub fn last_run_or_panic(&self) -> u128 {
         self.debounce.lock().unwrap().elapsed().unwrap().as_millis()
     }
 
@@ -206,3 +573,158 @@ impl WatcherState {
         self.last_path.clone()
     }
 }
+
+/// Watchable that never triggers nor gets discarded, used to register listeners in tests
+#[cfg(test)]
+pub struct NullWatchable(pub String);
+
+#[cfg(test)]
+impl std::fmt::Display for NullWatchable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+#[async_trait]
+impl Watchable for NullWatchable {
+    async fn trigger(
+        &self,
+        _: &mut ProjectImpl,
+        _: &Event,
+        _: &Arc<Broadcast>,
+    ) -> Result<Option<Receiver<bool>>> {
+        Ok(None)
+    }
+
+    async fn should_trigger(&self, _: &Event) -> bool {
+        false
+    }
+
+    async fn should_discard(&self, _: &Event) -> bool {
+        false
+    }
+
+    async fn discard(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_watch_subscribers_listeners() {
+    let mut subscribers = WatchSubscribers::new(&"Demo".into());
+    assert!(subscribers.add(NullWatchable("a".into())).is_ok());
+    assert!(subscribers.add(NullWatchable("b".into())).is_ok());
+    assert!(matches!(
+        subscribers.add(NullWatchable("a".into())),
+        Err(crate::Error::DuplicateWatchable(key)) if key == "a"
+    ));
+
+    assert_eq!(subscribers.listener_count(), 2);
+    assert!(subscribers.contains_key("a"));
+    assert!(!subscribers.contains_key("c"));
+}
+
+#[tokio::test]
+async fn test_watcher_handle_abort_on_drop() {
+    let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+    let handle = WatcherHandle {
+        inner: tokio::spawn(async move {
+            let _tx = tx;
+            std::future::pending::<()>().await
+        }),
+        ignore: Arc::new(RwLock::new(Watcher::compile_ignore(&[]).unwrap())),
+        watcher: Arc::new(Mutex::new(
+            <notify::RecommendedWatcher as notify::Watcher>::new(
+                |_: notify::Result<notify::Event>| {},
+            )
+            .unwrap(),
+        )),
+        pending: Default::default(),
+    };
+    assert!(!handle.has_pending_events());
+    drop(handle);
+
+    // NOTE: sender is dropped only once the task gets cancelled
+    let cancelled = tokio::time::timeout(Duration::from_millis(100), rx).await;
+    assert!(matches!(cancelled, Ok(Err(_))));
+}
+
+#[tokio::test]
+async fn test_watch_subscribers_discard_errors() {
+    struct FailingWatchable;
+
+    impl std::fmt::Display for FailingWatchable {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "failing")
+        }
+    }
+
+    #[async_trait]
+    impl Watchable for FailingWatchable {
+        async fn trigger(
+            &self,
+            _: &mut ProjectImpl,
+            _: &Event,
+            _: &Arc<Broadcast>,
+        ) -> Result<Option<Receiver<bool>>> {
+            Ok(None)
+        }
+
+        async fn should_trigger(&self, _: &Event) -> bool {
+            false
+        }
+
+        async fn should_discard(&self, _: &Event) -> bool {
+            false
+        }
+
+        async fn discard(&self) -> Result<()> {
+            Err(crate::Error::Watch("discard failed".into()))
+        }
+    }
+
+    let mut subscribers = WatchSubscribers::new(&"Demo".into());
+    subscribers.add(FailingWatchable).unwrap();
+    subscribers.remove(&"failing").await;
+
+    assert_eq!(subscribers.stats().discard_errors, 1);
+    assert_eq!(subscribers.listener_count(), 0);
+
+    subscribers.reset();
+    assert_eq!(subscribers.stats(), WatchStats::default());
+}
+
+#[test]
+fn test_watcher_drops_debounced_events() {
+    let root = std::env::temp_dir().join("xbase-test-watcher-debounce");
+    std::fs::create_dir_all(&root).unwrap();
+    let state = WatcherState::new();
+    let (sender, _receiver) = mpsc::unbounded_channel();
+    let new_watcher = |debounce| {
+        let abort = Default::default();
+        Watcher::new(
+            &"Demo".into(),
+            &state,
+            &sender,
+            &abort,
+            &root,
+            &vec![],
+            debounce,
+        )
+        .unwrap()
+    };
+    let watcher = new_watcher(Duration::from_secs(60));
+    let last_path = state.last_path();
+    let a = event::test_event(EventKind::FileUpdated, "/xbase/a.swift", &last_path);
+    let b = event::test_event(EventKind::FileUpdated, "/xbase/b.swift", &last_path);
+
+    assert!(watcher.should_forward(&a));
+    state.update_path_debounce(a.path());
+    assert!(
+        !watcher.should_forward(&a),
+        "same path within window is dropped"
+    );
+    assert!(watcher.should_forward(&b), "other paths are not coalesced");
+    assert!(new_watcher(Duration::ZERO).should_forward(&a));
+}
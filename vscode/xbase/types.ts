@@ -5,6 +5,14 @@ export namespace TaskKind {
       return { processing: "Running", done: "Ran", value: "Run" };
     } else if (isBuild(kind)) {
       return { processing: "Building", done: "Built", value: "Build" };
+    } else if (isTest(kind)) {
+      return { processing: "Testing", done: "Tested", value: "Test" };
+    } else if (isProfile(kind)) {
+      return {
+        processing: "Profile Building",
+        done: "Profile Built",
+        value: "Profile",
+      };
     } else if (isCompile(kind)) {
       return { processing: "Compiling", done: "Compiled", value: "Compile" };
     } else if (isGenerate(kind)) {
@@ -13,12 +21,17 @@ export namespace TaskKind {
         done: "Generated",
         value: "Generate",
       };
+    } else if (isClean(kind)) {
+      return { processing: "Cleaning", done: "Cleaned", value: "Clean" };
     }
   };
   export const isRun = (kind: TaskKind) => kind === "Run";
   export const isBuild = (kind: TaskKind) => kind === "Build";
+  export const isTest = (kind: TaskKind) => kind === "Test";
+  export const isProfile = (kind: TaskKind) => kind === "Profile";
   export const isGenerate = (kind: TaskKind) => kind === "Generate";
   export const isCompile = (kind: TaskKind) => kind === "Compile";
+  export const isClean = (kind: TaskKind) => kind === "Clean";
 }
 
 // AUTOGENERATED
@@ -60,6 +73,14 @@ export type TaskKind =
    * Run Task
    */
   | "Run"
+  /**
+   * Test Task
+   */
+  | "Test"
+  /**
+   * Build with debug symbols for profiling (i.e. Instruments)
+   */
+  | "Profile"
   /**
    * Compile Project (maybe setup)
    */
@@ -67,7 +88,16 @@ export type TaskKind =
   /**
    * Generate xcodeproj
    */
-  | "Generate";
+  | "Generate"
+  /**
+   * Clean build artifacts
+   */
+  | "Clean";
+
+/**
+ * Cause of [`TaskStatus::Error`], only created through [`TaskStatus::error`]
+ */
+export type ErrorCause = string;
 
 /**
  * What the status of task is currently under progress?
@@ -84,7 +114,21 @@ export type TaskStatus =
   /**
    * Processing Task,
    */
-  | "Processing";
+  | "Processing"
+  /**
+   * Non-fatal issue (e.g. lint warnings)
+   */
+  | "Warning"
+  /**
+   * No work in progress
+   */
+  | "Idle"
+  /**
+   * Task failed with a short human-readable cause
+   */
+  | { Error: ErrorCause };
+
+export type U64 = number;
 
 /**
  * Build Settings used in building/running a target/scheme
@@ -95,9 +139,9 @@ export type BuildSettings =
    */
   {
     /**
-     * Target to build
+     * Target to build, may be omitted when building a scheme
      */
-    target: string;
+    target?: string;
     /**
      * Configuration to build with, default Debug
      */
@@ -106,6 +150,26 @@ export type BuildSettings =
      * Scheme to build with
      */
     scheme: string | null;
+    /**
+     * Arguments to pass to the running application
+     */
+    launch_args?: string[];
+    /**
+     * Environment variables to set for the running application
+     */
+    env?: Record<string, string>;
+    /**
+     * Code signing identity used for distribution builds
+     */
+    signing_identity?: string | null;
+    /**
+     * Environment variables to set while building, e.g. `XCODE_XCCONFIG_FILE`
+     */
+    env_vars?: Record<string, string>;
+    /**
+     * Seconds after which building gets killed and fails, no timeout by default
+     */
+    timeout?: U64 | null;
   };
 
 /**
@@ -122,6 +186,23 @@ export type DeviceLookup =
  */
 export type Runners = Record<string, DeviceLookup[]>;
 
+/**
+ * Platform a target is built for or a device runs
+ */
+export type Platform =
+  | "iOS"
+  | "watchOS"
+  | "tvOS"
+  | "macOS"
+  | "visionOS"
+  | "Unknown";
+export type Usize = number;
+
+/**
+ * Kind of product a target produces
+ */
+export type TargetKind = "Executable" | "Library" | "Test" | "Plugin";
+
 /**
  * Target specfic information
  */
@@ -129,7 +210,26 @@ export type TargetInfo =
   /**
    * Target specfic information
    */
-  { platform: string; configurations: string[] };
+  {
+    platform: Platform;
+    configurations: string[];
+    /**
+     * Host application the target tests run inside of (Xcode TEST_HOST/BUNDLE_LOADER)
+     */
+    test_host?: string | null;
+    /**
+     * Cached number of source files, see [`TargetInfo::estimated_source_count`]
+     */
+    source_count?: Usize | null;
+    /**
+     * Kind of product the target produces, only known for swift packages
+     */
+    kind?: TargetKind | null;
+    /**
+     * Names of targets the target depends on
+     */
+    dependencies?: string[];
+  };
 export type ProjectInfo = {
   /**
    * Get watched configurations for given root
@@ -139,6 +239,22 @@ export type ProjectInfo = {
    * Get targets information for a registers project with a given root
    */
   targets: Record<string, TargetInfo>;
+  /**
+   * Configuration names across all targets
+   */
+  configurations: string[];
+  /**
+   * Whether the project git working tree has uncommitted changes
+   */
+  has_uncommitted_changes: boolean;
+  /**
+   * Xcode project found at the root of a swift package
+   */
+  adjacent_xcode_project: string | null;
+  /**
+   * Whether targets span more than one platform, i.e. clients should ask for a platform
+   */
+  is_multi_platform: boolean;
 };
 
 /**
@@ -147,6 +263,7 @@ export type ProjectInfo = {
 export type State =
   | { key: "runners"; value: Runners }
   | { key: "projectInfo"; value: ProjectInfo };
+export type U32 = number;
 
 /**
  * Representation of Messages that clients needs to process
@@ -160,6 +277,13 @@ export type Message =
       args: { content: string; level: ContentLevel };
     }
   | { type: "Log"; args: { content: string; level: ContentLevel } }
+  | {
+      /**
+       * Log message with shared content, cheap to clone for large process output
+       */
+      type: "LargeLog";
+      args: { content: string; level: ContentLevel };
+    }
   | {
       /**
        * Open Logger
@@ -168,9 +292,16 @@ export type Message =
     }
   | {
       /**
-       * Reload Language server
+       * Clear Logger
+       */
+      type: "ClearLogger";
+    }
+  | {
+      /**
+       * Reload Language servers with given names, or all servers if None
        */
       type: "ReloadLspServer";
+      args: string[] | null;
     }
   | {
       /**
@@ -187,6 +318,20 @@ export type Message =
       args: { content: string; level: ContentLevel };
     }
   | { type: "FinishCurrentTask"; args: { status: TaskStatus } }
+  | {
+      /**
+       * Update client list of project targets
+       */
+      type: "UpdateTargetList";
+      args: string[];
+    }
+  | {
+      /**
+       * Update statusline state
+       */
+      type: "UpdateStatusline";
+      args: { status: TaskStatus };
+    }
   | {
       /**
        * Notify client that something is being watched
@@ -200,6 +345,48 @@ export type Message =
        */
       type: "SetState";
       args: State;
+    }
+  | {
+      /**
+       * Execute a shell command on client side (opt-in, see allow_shell_tasks)
+       */
+      type: "ExecuteShellCommand";
+      args: string;
+    }
+  | {
+      /**
+       * Set a client side variable (i.e. vim.g[key]) to a given value
+       */
+      type: "SetVariable";
+      args: { key: string; value: string };
+    }
+  | {
+      /**
+       * Report a diagnostic for a given file and line
+       */
+      type: "Diagnostic";
+      args: { path: string; line: U32; content: string; level: ContentLevel };
+    }
+  | {
+      /**
+       * Temporarily highlight a range in file (1-based lines, 0-based columns)
+       */
+      type: "HighlightRange";
+      args: {
+        file: string;
+        start_line: U32;
+        start_col: U32;
+        end_line: U32;
+        end_col: U32;
+        group: string;
+      };
+    }
+  | {
+      /**
+       * Open a given file in client editor
+       */
+      type: "RevealFile";
+      args: string;
     };
 
 /**
@@ -231,7 +418,6 @@ export type Response =
    * Server Response
    */
   { data?: JSONValue; error?: ServerError };
-export type U32 = number;
 
 /**
  * Register a project root
@@ -274,6 +460,42 @@ export type DropRequest =
    */
   { id: U32; roots: string[] };
 
+/**
+ * Notify clients of all registered projects (e.g. daemon upgrade or global error)
+ */
+export type BroadcastGlobalRequest =
+  /**
+   * Notify clients of all registered projects (e.g. daemon upgrade or global error)
+   */
+  { content: string; level: ContentLevel };
+
+/**
+ * Get available devices to run built projects with
+ */
+export type DevicesRequest =
+  /**
+   * Get available devices to run built projects with
+   */
+  {};
+
+/**
+ * Request to open a registered project in Xcode
+ */
+export type OpenXcodeRequest =
+  /**
+   * Request to open a registered project in Xcode
+   */
+  { root: string };
+
+/**
+ * Request to reveal README of a registered project in clients
+ */
+export type ReadmeRequest =
+  /**
+   * Request to reveal README of a registered project in clients
+   */
+  { root: string };
+
 /**
  * Requests clinets can make
  */
@@ -305,4 +527,32 @@ export type Request =
        */
       method: "drop";
       args: DropRequest;
+    }
+  | {
+      /**
+       * Notify clients of all registered projects
+       */
+      method: "broadcast_global";
+      args: BroadcastGlobalRequest;
+    }
+  | {
+      /**
+       * Get available devices
+       */
+      method: "devices";
+      args: DevicesRequest;
+    }
+  | {
+      /**
+       * Open project in Xcode
+       */
+      method: "open_xcode";
+      args: OpenXcodeRequest;
+    }
+  | {
+      /**
+       * Reveal project README
+       */
+      method: "readme";
+      args: ReadmeRequest;
     };